@@ -0,0 +1,30 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use gob_rs::{core::Gob, index::GobIndex, options::{DuplicatePolicy, OffsetMode, ParseOptions}};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Parsing arbitrary input must fail gracefully rather than panic.
+    let _ = Gob::from_bytes(data);
+
+    let options = ParseOptions::new()
+        .duplicates(DuplicatePolicy::KeepLast)
+        .offsets(OffsetMode::Detect);
+
+    let _ = Gob::from_reader_with_options(&mut Cursor::new(data), options);
+
+    let _ = Gob::layout_report(&mut Cursor::new(data));
+
+    if let Ok(mut index) = GobIndex::new(Cursor::new(data)) {
+        for i in 0..index.entries().len() {
+            let _ = index.read_by_index(i);
+        }
+    }
+
+    // Archives which parse must also be written again.
+    if let Ok(gob) = Gob::from_bytes(data) {
+        let _ = gob.as_bytes();
+    }
+});
@@ -0,0 +1,256 @@
+//! Builder module of the library
+
+use std::{
+    io::Write, path::{Path, PathBuf}
+};
+
+#[cfg(feature = "compression")]
+use crate::compression::CompressionCodec;
+use crate::{core::Gob, error::GobError, format::{FilenameCase, FilenameEncoding, GobFormat}, path};
+
+/// A builder for constructing [`Gob`] objects, configuring how the archive is
+/// written in a single place.
+/// 
+/// # Examples
+/// 
+/// Builds an archive of the Dark Forces variant, whose files are written sorted
+/// by their filepaths:
+/// 
+/// ```
+/// use gob_rs::{builder::GobBuilder, format::GobFormat};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let gob = GobBuilder::new()
+///         .format(GobFormat::DarkForces)
+///         .deterministic(true)
+///         .add_file("FOO.BAR", b"foobar".to_vec())
+///         .add_file("FIZZ.BUZ", b"fizzbuzz".to_vec())
+///         .build()?;
+/// 
+///     assert_eq!(gob.format(), GobFormat::DarkForces);
+/// 
+///     assert!(gob.is_deterministic());
+/// 
+///     assert_eq!(gob.files.len(), 2);
+/// 
+///     Ok(())
+/// }
+/// ```
+/// 
+/// Fails fast on problems which would otherwise only surface when writing:
+/// 
+/// ```
+/// use gob_rs::{builder::GobBuilder, error::GobError};
+/// 
+/// let result = GobBuilder::new()
+///     .case_insensitive(true)
+///     .add_file("foo.bar", b"foobar".to_vec())
+///     .add_file("FOO.BAR", b"FOOBAR".to_vec())
+///     .build();
+/// 
+/// assert!(matches!(result, Err(GobError::DuplicateFilepath(_))));
+/// 
+/// let result = GobBuilder::new()
+///     .add_file("a".repeat(200), b"a".to_vec())
+///     .build();
+/// 
+/// assert!(matches!(result, Err(GobError::FilepathTooLong { .. })));
+/// ```
+/// 
+/// Writes an archive directly:
+/// 
+/// ```
+/// use gob_rs::builder::GobBuilder;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut data: Vec<u8> = Vec::new();
+/// 
+///     GobBuilder::new()
+///         .add_file("foo.bar", b"foobar".to_vec())
+///         .write_to(&mut data)?;
+/// 
+///     assert_eq!(&data[..4], b"GOB ");
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct GobBuilder {
+    gob: Gob,
+    /// The filepaths added more than once, each time after the first.
+    duplicates: Vec<PathBuf>,
+}
+
+impl GobBuilder {
+    /// Creates a new [`GobBuilder`] object for an empty archive with the
+    /// default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the variant of the GOB format the archive is written in.
+    /// See [`Gob::set_format`].
+    pub fn format(mut self, format: GobFormat) -> Self {
+        self.gob.set_format(format);
+
+        self
+    }
+
+    /// Sets the encoding the filepaths within the archive are written in.
+    /// See [`Gob::set_filename_encoding`].
+    pub fn filename_encoding(mut self, filename_encoding: FilenameEncoding) -> Self {
+        self.gob.set_filename_encoding(filename_encoding);
+
+        self
+    }
+
+    /// Sets the ASCII case the filepaths within the archive are written in.
+    /// See [`Gob::set_filename_case`].
+    pub fn filename_case(mut self, filename_case: FilenameCase) -> Self {
+        self.gob.set_filename_case(filename_case);
+
+        self
+    }
+
+    /// Sets whether files are written sorted by their filepaths.
+    /// See [`Gob::set_deterministic`].
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.gob.set_deterministic(deterministic);
+
+        self
+    }
+
+    /// Sets whether files with identical data share a single copy of it when
+    /// written. See [`Gob::set_deduplicating`].
+    pub fn deduplicate(mut self, deduplicate: bool) -> Self {
+        self.gob.set_deduplicating(deduplicate);
+
+        self
+    }
+
+    /// Sets the alignment, in bytes, of the offsets the data of files is written at.
+    /// See [`Gob::set_data_alignment`].
+    pub fn data_alignment(mut self, data_alignment: u32) -> Self {
+        self.gob.set_data_alignment(data_alignment);
+
+        self
+    }
+
+    /// Sets the offset of the file definitions written into the header.
+    /// See [`Gob::set_body_offset`].
+    pub fn body_offset(mut self, body_offset: u32) -> Self {
+        self.gob.set_body_offset(body_offset);
+
+        self
+    }
+
+    /// Sets the size the written archive is padded to with zero bytes.
+    /// See [`Gob::set_padded_size`].
+    pub fn pad_to(mut self, size: u64) -> Self {
+        self.gob.set_padded_size(Some(size));
+
+        self
+    }
+
+    /// Sets the codec the data of files is compressed with.
+    /// See [`Gob::set_compression`].
+    #[cfg(feature = "compression")]
+    pub fn compression(mut self, compression: CompressionCodec) -> Self {
+        self.gob.set_compression(compression);
+
+        self
+    }
+
+    /// Sets whether filepaths within the archive are treated case-insensitively.
+    /// See [`Gob::set_case_insensitive`].
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.gob.set_case_insensitive(case_insensitive);
+
+        self
+    }
+
+    /// Adds a file at a given path with given data to the archive.
+    /// 
+    /// The path may be separated by slashes or backslashes. Adding a path more
+    /// than once makes [`GobBuilder::build`] fail.
+    pub fn add_file(mut self, path: impl Into<PathBuf>, data: Vec<u8>) -> Self {
+        let filepath = path::normalize(&path.into());
+
+        if self.gob.files.contains_key(&filepath) {
+            self.duplicates.push(filepath);
+        } else {
+            self.gob.files.insert(filepath, data);
+        }
+
+        self
+    }
+
+    /// Adds the files of a directory at a given [`Path`] to the archive, at their
+    /// filepaths relative to the directory. See [`Gob::from_directory`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::builder::GobBuilder;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_builder_add_dir");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(path.join("mission"))?;
+    /// 
+    ///     std::fs::write(path.join("mission").join("foo.bar"), b"foobar")?;
+    /// 
+    ///     let gob = GobBuilder::new()
+    ///         .add_dir(&path)?
+    ///         .add_file("fizz.buzz", b"fizzbuzz".to_vec())
+    ///         .build()?;
+    /// 
+    ///     assert_eq!(gob.get("mission\\foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     assert_eq!(gob.len(), 2);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_dir(mut self, path: &Path) -> Result<Self, GobError> {
+        for (filepath, data) in Gob::from_directory(path)? {
+            self = self.add_file(filepath, data);
+        }
+
+        Ok(self)
+    }
+
+    /// Builds the [`Gob`] object, checking that it can be written as a GOB file.
+    /// 
+    /// Fails with the first problem found; see [`Gob::validate`] for what is
+    /// checked. Paths added more than once are reported as
+    /// [`GobError::DuplicateFilepath`].
+    pub fn build(self) -> Result<Gob, GobError> {
+        self.check()?;
+
+        Ok(self.gob)
+    }
+
+    /// Writes the data for a GOB file representing the archive to a given writer.
+    /// See [`Gob::write_to`].
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), GobError> {
+        self.check()?;
+
+        self.gob.write_to(writer)
+    }
+
+    fn check(&self) -> Result<(), GobError> {
+        if let Some(filepath) = self.duplicates.first() {
+            return Err(GobError::DuplicateFilepath(filepath.clone()));
+        }
+
+        match self.gob.validate() {
+            Ok(()) => Ok(()),
+            Err(errors) => Err(errors.into_iter().next().expect("Validation should fail with at least one error")),
+        }
+    }
+}
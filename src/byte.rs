@@ -1,15 +1,77 @@
-use std::string::FromUtf8Error;
+use std::{
+    io::{self, ErrorKind, Read, Seek, SeekFrom}, string::FromUtf8Error
+};
+
+use crate::error::GobError;
 
 pub fn string_from_bytes(bytes: &[u8]) -> Result<String, FromUtf8Error> {
     String::from_utf8(Vec::from(bytes))
 }
 
+/// Reads exactly enough bytes to fill a given buffer, reporting a reader
+/// ending prematurely as [`GobError::Truncated`] naming a given field.
+pub fn read_exact<R: Read>(reader: &mut R, buffer: &mut [u8], field: &'static str) -> Result<(), GobError> {
+    reader.read_exact(buffer).map_err(|error| match error.kind() {
+        ErrorKind::UnexpectedEof => GobError::Truncated { field },
+        _ => GobError::Io(error),
+    })
+}
+
+/// A reader treating a given position of an underlying reader as its start,
+/// such that seeks and positions are relative to it.
+pub struct Embedded<'a, R> {
+    reader: &'a mut R,
+    start: u64,
+}
+
+impl<'a, R: Seek> Embedded<'a, R> {
+    pub fn new(reader: &'a mut R, start: u64) -> Self {
+        Self {
+            reader,
+            start,
+        }
+    }
+}
+
+impl<R: Read> Read for Embedded<'_, R> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buffer)
+    }
+}
+
+impl<R: Seek> Seek for Embedded<'_, R> {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let position = match position {
+            SeekFrom::Start(offset) => match self.start.checked_add(offset) {
+                Some(offset) => SeekFrom::Start(offset),
+                None => {
+                    return Err(io::Error::new(ErrorKind::InvalidInput, "Seek past the maximum offset."));
+                }
+            },
+            position => position,
+        };
+
+        let previous = self.reader.stream_position()?;
+
+        let offset = self.reader.seek(position)?;
+
+        match offset.checked_sub(self.start) {
+            Some(offset) => Ok(offset),
+            None => {
+                // Stay within the embedded archive, like a rejected seek should.
+                self.reader.seek(SeekFrom::Start(previous))?;
+
+                Err(io::Error::new(ErrorKind::InvalidInput, "Seek before the start of the embedded archive."))
+            }
+        }
+    }
+}
+
 macro_rules! slice {
-    ($file:ident, $size:literal) => {{
+    ($file:expr, $size:literal, $field:literal) => {{
         let mut slice_array = [0 as u8; $size];
 
-        $file
-            .read_exact(&mut slice_array)?;
+        crate::byte::read_exact($file, &mut slice_array, $field)?;
 
         slice_array
     }};
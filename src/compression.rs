@@ -0,0 +1,139 @@
+//! Compression module of the library
+
+use std::io::{Read, Write};
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+
+use crate::error::GobError;
+
+/// The bytes preceding the uncompressed length and the zlib stream of a file
+/// compressed with [`CompressionCodec::Zlib`].
+const ZLIB_MAGIC: &[u8; 4] = b"ZLIB";
+
+/// A codec the data of files is compressed with when writing a GOB archive and
+/// decompressed with when parsing one.
+/// 
+/// The GOB format has no notion of compression, so archives containing
+/// compressed files are a nonstandard variant which the games cannot load.
+/// Each compressed file is marked by a short prefix followed by its
+/// uncompressed length, while files which do not inflate to exactly that
+/// length are read as they are. Requires the `compression` feature.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::{io::Cursor, path::PathBuf};
+/// use gob_rs::{compression::CompressionCodec, core::Gob, options::ParseOptions};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.set_compression(CompressionCodec::Zlib);
+/// 
+///     gob.files.insert(
+///         PathBuf::from("foo.bar"),
+///         b"foobar".repeat(100),
+///     );
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     assert!(data.len() < 600);
+/// 
+///     let options = ParseOptions::new().compression(CompressionCodec::Zlib);
+/// 
+///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+/// 
+///     assert_eq!(parsed.get("foo.bar"), Some(&b"foobar".repeat(100)[..]));
+/// 
+///     // The parsed archive keeps the codec, so it is written compressed again.
+///     assert_eq!(parsed.compression(), CompressionCodec::Zlib);
+/// 
+///     assert_eq!(parsed.as_bytes()?, data);
+/// 
+///     Ok(())
+/// }
+/// ```
+/// 
+/// Files which merely start with the prefix are read as they are:
+/// 
+/// ```
+/// use std::{io::Cursor, path::PathBuf};
+/// use gob_rs::{compression::CompressionCodec, core::Gob, options::ParseOptions};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(
+///         PathBuf::from("foo.bar"),
+///         b"ZLIB\x06\0\0\0\0\0\0\0foobar".to_vec(),
+///     );
+/// 
+///     let options = ParseOptions::new().compression(CompressionCodec::Zlib);
+/// 
+///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(gob.as_bytes()?), options)?;
+/// 
+///     assert!(parsed == gob);
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CompressionCodec {
+    /// No compression, producing standard GOB archives.
+    #[default]
+    None,
+    /// Compression using zlib (RFC 1950).
+    Zlib,
+}
+
+impl CompressionCodec {
+    /// Compresses the data of a file.
+    pub(crate) fn compress(self, data: &[u8]) -> Result<Vec<u8>, GobError> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Zlib => {
+                let mut prefix = ZLIB_MAGIC.to_vec();
+
+                prefix.extend(&(data.len() as u64).to_le_bytes());
+
+                let mut encoder = ZlibEncoder::new(prefix, Compression::default());
+
+                encoder.write_all(data)?;
+
+                Ok(encoder.finish()?)
+            }
+        }
+    }
+
+    /// Decompresses the data of a file, returning it as it is unless it was
+    /// compressed with the codec.
+    pub(crate) fn decompress(self, data: Vec<u8>) -> Result<Vec<u8>, GobError> {
+        match self {
+            Self::None => Ok(data),
+            Self::Zlib => {
+                let Some((len, stream)) = data
+                    .strip_prefix(ZLIB_MAGIC)
+                    .and_then(|rest| rest.split_first_chunk::<8>())
+                else {
+                    return Ok(data);
+                };
+
+                let expected_len = u64::from_le_bytes(*len);
+
+                // Reading one byte past the expected length tells apart streams
+                // inflating to more, without inflating all of them.
+                let mut decompressed: Vec<u8> = Vec::new();
+
+                let inflated = ZlibDecoder::new(stream)
+                    .take(expected_len.saturating_add(1))
+                    .read_to_end(&mut decompressed);
+
+                // Raw data may start with the prefix by chance.
+                match inflated {
+                    Ok(_) if decompressed.len() as u64 == expected_len => Ok(decompressed),
+                    _ => Ok(data),
+                }
+            }
+        }
+    }
+}
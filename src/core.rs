@@ -1,10 +1,14 @@
 //! Core module of the library
 
 use std::{
-    collections::HashMap, fs, io::{Error, ErrorKind, Read, Seek, SeekFrom}, path::{Path, PathBuf}
+    collections::{BTreeMap, HashMap, HashSet}, ffi::OsString, fmt, fs, io::{self, BufWriter, Cursor, Error, ErrorKind, Read, Seek, SeekFrom, Write}, mem, path::{Path, PathBuf}, time::SystemTime
 };
 
-use crate::byte;
+use indexmap::IndexMap;
+
+#[cfg(feature = "compression")]
+use crate::compression::CompressionCodec;
+use crate::{byte, crc, diff::{GobDiff, Modified}, error::GobError, format::{FilenameCase, FilenameEncoding, GobFormat}, index::GobIndex, options::{DirectoryOptions, DuplicatePolicy, OffsetMode, ParseMode, ParseOptions, SymlinkPolicy}, path, view::GobView};
 
 /// An object representing a GOB archive.
 /// 
@@ -58,7 +62,7 @@ use crate::byte;
 ///     b"fizzbuzz".to_vec(),
 /// );
 /// 
-/// let file_count = gob.files.len();
+/// let file_count = gob.len();
 /// 
 /// assert_eq!(file_count, 2);
 /// ```
@@ -85,52 +89,182 @@ use crate::byte;
 ///     println!("path: {} data: {:?}", filepath.display(), data);
 /// }
 /// ```
+/// 
+/// Clones the archive, including its settings:
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::{core::Gob, format::GobFormat};
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.set_format(GobFormat::DarkForces);
+/// 
+/// gob.files.insert(
+///     PathBuf::from("FOO.BAR"),
+///     b"foobar".to_vec(),
+/// );
+/// 
+/// let copy = gob.clone();
+/// 
+/// gob.files.clear();
+/// 
+/// assert_eq!(copy.len(), 1);
+/// 
+/// assert_eq!(copy.format(), GobFormat::DarkForces);
+/// 
+/// assert_ne!(copy, gob);
+/// ```
+#[derive(Clone)]
 pub struct Gob {
     /// A [`GobMap`], representing the structure of the archive.
+    /// 
+    /// Inserting into the map directly defers checking the filepaths until the
+    /// archive is written; prefer [`Gob::insert_checked`] to catch filepaths
+    /// which cannot be written, e.g. because they are too long, immediately.
     pub files: GobMap,
+    case_insensitive: bool,
+    deterministic: bool,
+    deduplicating: bool,
+    format: GobFormat,
+    version: u32,
+    filename_encoding: FilenameEncoding,
+    filename_case: FilenameCase,
+    data_alignment: u32,
+    body_offset: u32,
+    padded_size: Option<u64>,
+    metadata: HashMap<PathBuf, EntryMeta>,
+    raw_layout: Option<RawLayout>,
+    #[cfg(feature = "compression")]
+    compression: CompressionCodec,
+}
+
+type Entry<'a> = (&'a PathBuf, &'a Vec<u8>);
+
+/// The start and end offsets of a range of bytes within an archive.
+type Region = (u64, u64);
+
+/// The header and the file definitions of a parsed archive.
+pub(crate) struct ParsedTable {
+    pub(crate) format: GobFormat,
+    pub(crate) version: u32,
+    /// The offset of the file count, which the file definitions follow.
+    pub(crate) body_offset: u64,
+    /// The length of the archive in bytes.
+    pub(crate) length: u64,
+    pub(crate) file_definitions: Vec<FileDefinition>,
+    /// The filepaths stored with forward slashes instead of backslashes.
+    pub(crate) mixed_separators: Vec<PathBuf>,
+}
+
+/// The encoded header and file definitions of an archive.
+struct Table {
+    /// The bytes preceding the data of the files.
+    head: Vec<u8>,
+    /// The amount of zero bytes preceding the data of each file, or `None` if
+    /// the data is shared with a previous file and thus not written again.
+    padding: Vec<Option<u32>>,
+    /// The offset of the data of each file.
+    offsets: Vec<u32>,
+    /// The bytes following the data of the files.
+    tail: Vec<u8>,
 }
 
 impl Gob {
-    fn get_files_from_directory(
-        files: &mut GobMap,
-        directory: &mut fs::ReadDir,
-        root: Option<&Path>,
-    ) -> std::io::Result<()> {
-        for item in directory {
-            let item = item?;
+    fn get_files_from_directory(gob: &mut Gob, root: &Path, options: &mut DirectoryOptions) -> Result<(), GobError> {
+        // The directories yet to be walked, each with the canonical paths of its
+        // ancestors if following symbolic links, walked iteratively so that deep
+        // trees cannot overflow the stack.
+        let mut directories: Vec<(PathBuf, Vec<PathBuf>)> = vec![(root.to_path_buf(), Vec::new())];
+
+        while let Some((directory, mut ancestors)) = directories.pop() {
+            if options.symlinks == SymlinkPolicy::Follow {
+                let directory = fs::canonicalize(&directory)?;
+
+                if ancestors.contains(&directory) {
+                    continue;
+                }
+
+                ancestors.push(directory);
+            }
+
+            let mut items = fs::read_dir(&directory)?.collect::<Result<Vec<_>, _>>()?;
 
-            let path = item.path();
+            // Walk the items in the same order on every run, whatever order the
+            // file system lists them in.
+            items.sort_by_key(|item| item.file_name());
 
-            let root = match root {
-                Some(root) => root,
-                None => match path.parent() {
-                    Some(root) => root,
-                    None => {
-                        return Err(Error::new(ErrorKind::Other, "Unable to get parent directory from path."));
+            // Estimate the amount of files by the amount of items in the directory.
+            gob.files.reserve(items.len());
+
+            let mut subdirectories: Vec<(PathBuf, Vec<PathBuf>)> = Vec::new();
+
+            for item in items {
+                let path = item.path();
+
+                if let Some(filter) = &mut options.filter {
+                    if !filter(&path) {
+                        continue;
                     }
                 }
-            };
 
-            if path.is_file() {
-                let mut file = fs::File::open(&path)?;
+                let mut file_type = item.file_type()?;
 
-                let mut data: Vec<u8> = Vec::new();
+                if file_type.is_symlink() {
+                    match options.symlinks {
+                        SymlinkPolicy::Skip => continue,
+                        SymlinkPolicy::Follow => match fs::metadata(&path) {
+                            Ok(metadata) => file_type = metadata.file_type(),
+                            Err(_) if options.lenient => continue,
+                            Err(error) => {
+                                return Err(Error::new(error.kind(), format!("Unable to follow symbolic link: {}: {error}", path.display())).into());
+                            }
+                        },
+                        SymlinkPolicy::Error => {
+                            return Err(Error::new(ErrorKind::InvalidInput, format!("Path is a symbolic link: {}", path.display())).into());
+                        }
+                    }
+                }
 
-                file.read_to_end(&mut data)?;
+                if file_type.is_file() {
+                    let mut file = fs::File::open(&path)?;
 
-                let filepath: PathBuf = path
-                    .strip_prefix(root)
-                    .expect("Should be able to get relative path")
-                    .into();
+                    let mut data: Vec<u8> = Vec::new();
 
-                files.insert(filepath, data);
-            } else if path.is_dir() {
-                let mut directory = path.read_dir()?;
+                    file.read_to_end(&mut data)?;
 
-                Self::get_files_from_directory(files, &mut directory, Some(root))?;
-            } else {
-                return Err(Error::new(ErrorKind::InvalidInput, "Path is neither file nor directory."));
+                    let filepath = match path.strip_prefix(options.strip_prefix.as_deref().unwrap_or(root)) {
+                        Ok(filepath) => path::normalize(filepath),
+                        Err(_) => {
+                            return Err(Error::new(ErrorKind::InvalidInput, format!("File lies outside of prefix to strip: {}", path.display())).into());
+                        }
+                    };
+
+                    // Distinct filenames may still collide within the archive, e.g. a file
+                    // whose name contains a backslash and a file within a directory.
+                    if gob.files.contains_key(&filepath) {
+                        return Err(GobError::DuplicateFilepath(filepath));
+                    }
+
+                    if options.metadata {
+                        let metadata = file.metadata()?;
+
+                        gob.metadata.insert(filepath.clone(), EntryMeta {
+                            modified: metadata.modified().ok(),
+                            size: metadata.len(),
+                        });
+                    }
+
+                    gob.files.insert(filepath, data);
+                } else if file_type.is_dir() {
+                    subdirectories.push((path, ancestors.clone()));
+                } else if !options.lenient {
+                    return Err(Error::new(ErrorKind::InvalidInput, format!("Path is neither file nor directory: {}", path.display())).into());
+                }
             }
+
+            // Push the subdirectories in reverse, so that they are popped in order.
+            directories.extend(subdirectories.into_iter().rev());
         }
 
         Ok(())
@@ -139,6 +273,13 @@ impl Gob {
     /// Creates a new [`Gob`] object from a given [`Path`] to a directory,
     /// structured like a GOB archive.
     /// 
+    /// Symbolic links within the directory are skipped; see
+    /// [`Gob::from_directory_with_options`] for following them instead.
+    /// 
+    /// Files are ordered by their relative filepaths, compared like when writing
+    /// deterministically (see [`Gob::set_deterministic`]), regardless of the
+    /// order the file system lists them in.
+    /// 
     /// # Examples
     /// ```no_run
     /// use std::path::Path;
@@ -150,217 +291,6567 @@ impl Gob {
     ///     Ok(())
     /// }
     /// ```
-    pub fn from_directory(path: &Path) -> std::io::Result<Self> {
-        if !path.is_dir() {
-            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a directory."));
-        }
-
-        let mut directory = fs::read_dir(path)?;
-        
-        let mut files = GobMap::new();
-
-        Self::get_files_from_directory(&mut files, &mut directory, None)?;
-
-        Ok(Self { files })
+    /// 
+    /// Packs a directory into the same bytes on every run:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_order");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     for filepath in ["c/2.txt", "a.txt", "B.txt", "c/1.txt"] {
+    ///         let filepath = path.join(filepath);
+    /// 
+    ///         std::fs::create_dir_all(filepath.parent().unwrap())?;
+    /// 
+    ///         std::fs::write(filepath, b"foobar")?;
+    ///     }
+    /// 
+    ///     let gob = Gob::from_directory(&path)?;
+    /// 
+    ///     let filepaths: Vec<&PathBuf> = gob.files.keys().collect();
+    /// 
+    ///     assert_eq!(filepaths, ["a.txt", "B.txt", "c/1.txt", "c/2.txt"]);
+    /// 
+    ///     assert_eq!(gob.as_bytes()?, Gob::from_directory(&path)?.as_bytes()?);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Packs arbitrarily deeply nested directories:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_deep");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     let filepath: PathBuf = std::iter::repeat("d").take(500).chain(["foo.bar"]).collect();
+    /// 
+    ///     std::fs::create_dir_all(path.join(&filepath).parent().unwrap())?;
+    /// 
+    ///     std::fs::write(path.join(&filepath), b"foobar")?;
+    /// 
+    ///     let gob = Gob::from_directory(&path)?;
+    /// 
+    ///     assert_eq!(gob.get(&filepath), Some(&b"foobar"[..]));
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Fails on files which would share a filepath within the archive, rather
+    /// than dropping all but one of them:
+    /// 
+    /// ```
+    /// #[cfg(unix)]
+    /// fn main() -> std::io::Result<()> {
+    ///     use gob_rs::{core::Gob, error::GobError};
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_duplicate");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(path.join("foo"))?;
+    /// 
+    ///     std::fs::write(path.join("foo").join("bar"), b"foobar")?;
+    /// 
+    ///     std::fs::write(path.join("foo\\bar"), b"foobaz")?;
+    /// 
+    ///     assert!(matches!(Gob::from_directory(&path), Err(GobError::DuplicateFilepath(_))));
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// 
+    /// #[cfg(not(unix))]
+    /// fn main() {}
+    /// ```
+    pub fn from_directory(path: &Path) -> Result<Self, GobError> {
+        Self::from_directory_with(path, |_| true)
     }
 
-    const SIGNATURE: &'static [u8; 4] = b"GOB ";
-
-    const VERSION: u32 = 0x14;
-
-    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file.
+    /// Creates a new [`Gob`] object from a given [`Path`] to a directory,
+    /// structured like a GOB archive, including only the entries for which a
+    /// given filter returns `true`.
+    /// 
+    /// The filter receives the full path of each file and directory during
+    /// traversal, i.e. the given path joined with the path of the entry within
+    /// the directory; it is absolute if the given path is. Excluding a directory
+    /// excludes everything within it.
     /// 
     /// # Examples
     /// 
-    /// ```no_run
-    /// use std::path::Path;
+    /// Excludes editor junk and VCS metadata:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
     /// use gob_rs::core::Gob;
     /// 
     /// fn main() -> std::io::Result<()> {
-    ///     let gob = Gob::from_file(Path::new("/path/to/gob.GOB"))?;
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_with");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(path.join(".git"))?;
+    /// 
+    ///     std::fs::write(path.join(".git").join("HEAD"), b"ref")?;
+    /// 
+    ///     std::fs::write(path.join(".DS_Store"), b"junk")?;
+    /// 
+    ///     std::fs::write(path.join("foo.bar"), b"foobar")?;
+    /// 
+    ///     let gob = Gob::from_directory_with(&path, |path| {
+    ///         !path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'))
+    ///     })?;
+    /// 
+    ///     assert_eq!(gob.files.keys().collect::<Vec<_>>(), [&PathBuf::from("foo.bar")]);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
     /// 
     ///     Ok(())
     /// }
     /// ```
-    pub fn from_file(path: &Path) -> std::io::Result<Self> {
-        if !path.is_file() {
-            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file."));
-        }
-
-        let mut file = fs::File::open(path)?;
-
-        file.seek(SeekFrom::Start(0))?;
-
-        let signature = &byte::slice!(file, 4);
-
-        if signature != Self::SIGNATURE {
-            return Err(Error::new(ErrorKind::InvalidData, "Bad signature in header of GOB file."));
-        }
-
-        let version = u32::from_le_bytes(byte::slice!(file, 4));
-
-        if version != Self::VERSION {
-            return Err(Error::new(ErrorKind::InvalidData, "Bad version in header of GOB file."));
-        }
-
-        let body_offset = u32::from_le_bytes(byte::slice!(file, 4)) as u64;
-
-        file.seek(SeekFrom::Start(body_offset))?;
-
-        let file_count = u32::from_le_bytes(byte::slice!(file, 4));
-
-        let mut file_definitions: Vec<FileDefinition> = Vec::new();
-
-        for _ in 0..file_count {
-            let offset = u32::from_le_bytes(byte::slice!(file, 4)) as usize;
-
-            let size = u32::from_le_bytes(byte::slice!(file, 4)) as usize;
-
-            let filepath_bytes = byte::slice!(file, 128);
-
-            let filepath_end = filepath_bytes.iter().position(|&n| n == 0).unwrap_or(128);
-
-            let filepath = match byte::string_from_bytes(&filepath_bytes[..filepath_end]) {
-                Ok(filepath) => filepath,
-                Err(_) => {
-                    return Err(Error::new(ErrorKind::InvalidData, format!("Cannot convert following bytes to string: {filepath_bytes:?}")));
-                }
-            };
-
-            let filepath = PathBuf::from(filepath);
-
-            file_definitions.push(FileDefinition {
-                offset,
-                size,
-                filepath,
-            });
-        }
-
-        let mut files = GobMap::new();
-
-        for file_definition in file_definitions {
-            file.seek(SeekFrom::Start(file_definition.offset as u64))?;
-
-            let mut data: Vec<u8> = vec![0; file_definition.size];
-
-            file.read_exact(&mut data)?;
-
-            files.insert(file_definition.filepath, data);
-        }
-
-        Ok(Self { files })
+    pub fn from_directory_with<F: FnMut(&Path) -> bool>(path: &Path, filter: F) -> Result<Self, GobError> {
+        Self::from_directory_with_options(path, DirectoryOptions::new().filter(filter))
     }
 
-    /// Generates the data (bytes) for a GOB file representing the current archive object.
+    /// Creates a new [`Gob`] object from a given [`Path`] to a directory,
+    /// getting the filepaths within the archive by stripping a given prefix
+    /// from the paths of the files instead of the path of the directory.
+    /// 
+    /// The prefix is stripped lexically, so it must be given in the same form
+    /// as the path, e.g. both relative or both absolute. Fails with an error of
+    /// kind [`InvalidInput`](std::io::ErrorKind::InvalidInput) if a file lies
+    /// outside of the prefix. Also see [`DirectoryOptions::strip_prefix`].
     /// 
     /// # Examples
     /// 
+    /// Keeps the name of the packed directory:
+    /// 
     /// ```
     /// use std::path::PathBuf;
     /// use gob_rs::core::Gob;
     /// 
-    /// let mut gob = Gob::new();
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_rooted");
     /// 
-    /// gob.files.insert(
-    ///     PathBuf::from("foo.bar"),
-    ///     b"foobar".to_vec(),
-    /// );
+    ///     let _ = std::fs::remove_dir_all(&path);
     /// 
-    /// gob.files.insert(
-    ///     PathBuf::from("fizz.buzz"),
-    ///     b"fizzbuzz".to_vec(),
-    /// );
+    ///     std::fs::create_dir_all(path.join("mission"))?;
     /// 
-    /// let data = gob.as_bytes();
+    ///     std::fs::write(path.join("mission").join("foo.bar"), b"foobar")?;
     /// 
-    /// assert_eq!(&data[..4], Vec::from(b"GOB "));
+    ///     std::fs::write(path.join("fizz.buzz"), b"fizzbuzz")?;
     /// 
-    /// assert_eq!(&data[4..8], Vec::from(0x14u32.to_le_bytes()));
+    ///     let gob = Gob::from_directory_rooted(&path.join("mission"), &path)?;
     /// 
-    /// assert_eq!(&data[8..12], Vec::from(12u32.to_le_bytes()));
+    ///     assert_eq!(gob.files.keys().collect::<Vec<_>>(), [&PathBuf::from("mission/foo.bar")]);
     /// 
-    /// assert_eq!(&data[12..16], Vec::from(2u32.to_le_bytes()));
+    ///     assert!(Gob::from_directory_rooted(&path, &path.join("mission")).is_err());
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
     /// ```
-    pub fn as_bytes(self) -> Result<Vec<u8>, String> {
-        let mut bytes: Vec<u8> = Vec::new();
-
-        bytes.extend(Self::SIGNATURE);
-
-        bytes.extend(&Self::VERSION.to_le_bytes());
-
-        let body_offset: u32 = 12;
-
-        bytes.extend(&body_offset.to_le_bytes());
-
-        let file_count = self.files.len() as u32;
-
-        bytes.extend(&file_count.to_le_bytes());
-
-        let mut file_data_offset: u32 = 16 + 136 * file_count;
-
-        for (filepath, file_data) in &self.files {
-            bytes.extend(&file_data_offset.to_le_bytes());
-
-            let size = file_data.len() as u32;
-
-            file_data_offset += size;
-
-            bytes.extend(&size.to_le_bytes());
+    pub fn from_directory_rooted(path: &Path, strip_prefix: &Path) -> Result<Self, GobError> {
+        Self::from_directory_with_options(path, DirectoryOptions::new().strip_prefix(strip_prefix))
+    }
 
-            let filepath_bytes = filepath.as_os_str().as_encoded_bytes();
+    /// Creates a new [`Gob`] object from a given [`Path`] to a directory,
+    /// structured like a GOB archive, packing it according to given
+    /// [`DirectoryOptions`].
+    /// 
+    /// # Examples
+    /// 
+    /// Rejects symbolic links instead of skipping them:
+    /// 
+    /// ```
+    /// #[cfg(unix)]
+    /// fn main() -> std::io::Result<()> {
+    ///     use gob_rs::{core::Gob, options::{DirectoryOptions, SymlinkPolicy}};
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_symlinks");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(path.join("fizz"))?;
+    /// 
+    ///     std::fs::write(path.join("fizz").join("foo.bar"), b"foobar")?;
+    /// 
+    ///     // A symbolic link back to the directory itself.
+    ///     let _ = std::os::unix::fs::symlink(&path, path.join("fizz").join("loop"));
+    /// 
+    ///     assert_eq!(Gob::from_directory(&path)?.len(), 1);
+    /// 
+    ///     let options = DirectoryOptions::new().symlinks(SymlinkPolicy::Follow);
+    /// 
+    ///     assert_eq!(Gob::from_directory_with_options(&path, options)?.len(), 1);
+    /// 
+    ///     let options = DirectoryOptions::new().symlinks(SymlinkPolicy::Error);
+    /// 
+    ///     assert!(Gob::from_directory_with_options(&path, options).is_err());
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// 
+    /// #[cfg(not(unix))]
+    /// fn main() {}
+    /// ```
+    /// 
+    /// Skips broken symbolic links instead of failing:
+    /// 
+    /// ```
+    /// #[cfg(unix)]
+    /// fn main() -> std::io::Result<()> {
+    ///     use gob_rs::{core::Gob, options::{DirectoryOptions, SymlinkPolicy}};
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_from_directory_lenient");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(&path)?;
+    /// 
+    ///     std::fs::write(path.join("foo.bar"), b"foobar")?;
+    /// 
+    ///     let _ = std::os::unix::fs::symlink(path.join("missing"), path.join("broken"));
+    /// 
+    ///     let options = DirectoryOptions::new().symlinks(SymlinkPolicy::Follow);
+    /// 
+    ///     let error = Gob::from_directory_with_options(&path, options).unwrap_err();
+    /// 
+    ///     assert!(error.to_string().contains("broken"));
+    /// 
+    ///     let options = DirectoryOptions::new().symlinks(SymlinkPolicy::Follow).lenient(true);
+    /// 
+    ///     assert_eq!(Gob::from_directory_with_options(&path, options)?.len(), 1);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// 
+    /// #[cfg(not(unix))]
+    /// fn main() {}
+    /// ```
+    pub fn from_directory_with_options(path: &Path, mut options: DirectoryOptions) -> Result<Self, GobError> {
+        if !path.is_dir() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a directory.").into());
+        }
 
-            if filepath_bytes.len() > 128 {
-                return Err(format!("Filepath is longer than 128 bytes: {}", filepath.display()))
-            }
+        let mut gob = Self::new();
 
-            bytes.extend(filepath_bytes);
+        Self::get_files_from_directory(&mut gob, path, &mut options)?;
 
-            bytes.extend(vec![0; 128 - filepath_bytes.len()]);
+        if options.require_non_empty && gob.is_empty() {
+            return Err(GobError::EmptySource(path.to_path_buf()));
         }
 
-        for (_, file_data) in &self.files {
-            bytes.extend(file_data);
-        }
+        gob.files.sort_by_cached_key(|filepath, _| path::sort_key(filepath));
 
-        Ok(bytes)
+        Ok(gob)
     }
 
-    /// Creates a new [`Gob`] object.
+    /// Writes the files of the current archive object into a directory at a
+    /// given [`Path`], recreating the structure of the archive.
+    /// 
+    /// Missing directories are created as needed. Filepaths which would escape
+    /// the directory (i.e. which are absolute or contain `..` components) or
+    /// which contain components that are illegal as filenames on the current
+    /// platform (e.g. containing `:` on Windows) are rejected before anything is
+    /// written, the latter with [`GobError::IllegalPathComponent`].
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use gob_rs::core::Gob;
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz/foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("empty.bar"),
+    ///         Vec::new(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_directory");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     gob.to_directory(&path)?;
+    /// 
+    ///     assert_eq!(std::fs::read(path.join("fizz/foo.bar"))?, b"foobar");
+    /// 
+    ///     assert_eq!(std::fs::read(path.join("empty.bar"))?, b"");
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("../foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     assert!(matches!(
+    ///         gob.to_directory(&path),
+    ///         Err(GobError::Io(error)) if error.kind() == std::io::ErrorKind::InvalidInput,
+    ///     ));
+    /// 
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo\0.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     assert!(matches!(
+    ///         gob.to_directory(&path),
+    ///         Err(GobError::IllegalPathComponent { .. }),
+    ///     ));
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Extracts a GOB archive, whose filepaths are separated by backslashes, into
+    /// nested directories and packs it again:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz").join("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(&data[24..36], b"fizz\\foo.bar");
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_directory_nested");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     Gob::from_reader(&mut Cursor::new(&data))?.to_directory(&path)?;
+    /// 
+    ///     assert!(path.join("fizz").is_dir());
+    /// 
+    ///     assert_eq!(Gob::from_directory(&path)?.as_bytes()?, data);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_directory(&self, path: &Path) -> Result<(), GobError> {
+        self.to_directory_matching(path, |_| true)
+    }
+
+    /// Writes the files of the current archive object for which a given
+    /// predicate returns `true` into a directory at a given [`Path`], like
+    /// [`Gob::to_directory`].
+    /// 
+    /// Only the directories containing matching files are created, and only the
+    /// filepaths of matching files are checked.
+    /// 
+    /// # Examples
+    /// 
+    /// Extracts the textures of an archive, regardless of the case of their
+    /// extension:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("textures").join("FOO.BM"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("sounds").join("fizz.voc"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_directory_matching");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     gob.to_directory_matching(&path, |filepath| {
+    ///         filepath.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("bm"))
+    ///     })?;
+    /// 
+    ///     assert_eq!(std::fs::read(path.join("textures").join("FOO.BM"))?, b"foobar");
+    /// 
+    ///     assert!(!path.join("sounds").exists());
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_directory_matching<F: Fn(&Path) -> bool>(&self, path: &Path, predicate: F) -> Result<(), GobError> {
+        let files: Vec<(&PathBuf, &Vec<u8>)> = self.files
+            .iter()
+            .filter(|(filepath, _)| predicate(filepath))
+            .collect();
+
+        for (filepath, _) in &files {
+            Self::check_extractable(filepath)?;
+        }
+
+        for (filepath, data) in files {
+            let path = path.join(filepath);
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::write(&path, data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the files of the current archive object into a directory at a
+    /// given [`Path`] like [`Gob::to_directory`], writing the files concurrently.
+    /// 
+    /// All directories are created before any file is written, so that no two
+    /// threads create the same directory. Requires the `rayon` feature.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     for i in 0..100 {
+    ///         gob.files.insert(
+    ///             PathBuf::from(format!("dir{}", i % 10)).join(format!("{i}.bar")),
+    ///             format!("foobar{i}").into_bytes(),
+    ///         );
+    ///     }
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_directory_parallel");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     gob.to_directory_parallel(&path)?;
+    /// 
+    ///     assert_eq!(std::fs::read(path.join("dir7").join("37.bar"))?, b"foobar37");
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn to_directory_parallel(&self, path: &Path) -> Result<(), GobError> {
+        use rayon::prelude::*;
+
+        for filepath in self.files.keys() {
+            Self::check_extractable(filepath)?;
+        }
+
+        let files: Vec<(PathBuf, &Vec<u8>)> = self.files
+            .iter()
+            .map(|(filepath, data)| (path.join(filepath), data))
+            .collect();
+
+        let directories: HashSet<&Path> = files.iter().filter_map(|(path, _)| path.parent()).collect();
+
+        for directory in directories {
+            fs::create_dir_all(directory)?;
+        }
+
+        files
+            .par_iter()
+            .try_for_each(|(path, data)| fs::write(path, data))?;
+
+        Ok(())
+    }
+
+    /// Extracts all files of a GOB archive from a given reader into a directory
+    /// at a given [`Path`], copying the data of one file at a time instead of
+    /// parsing the whole archive into memory.
+    /// 
+    /// Like [`Gob::to_directory`], all filepaths are checked before anything is
+    /// written. If multiple files share a filepath, the one defined last is kept.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{fs, io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission/foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_extract_all");
+    /// 
+    ///     let _ = fs::remove_dir_all(&path);
+    /// 
+    ///     Gob::extract_all(&mut Cursor::new(gob.as_bytes()?), &path)?;
+    /// 
+    ///     assert_eq!(fs::read(path.join("mission").join("foo.bar"))?, b"foobar");
+    /// 
+    ///     assert_eq!(fs::read(path.join("fizz.buzz"))?, b"fizzbuzz");
+    /// 
+    ///     fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extract_all<R: Read + Seek>(reader: &mut R, path: &Path) -> Result<(), GobError> {
+        Self::extract_all_with_progress(reader, path, |_| {})
+    }
+
+    /// Extracts all files of a GOB archive from a given reader into a directory
+    /// at a given [`Path`] like [`Gob::extract_all`], calling a given closure
+    /// with the [`Progress`] after each file has been extracted.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_extract_all_with_progress");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     let mut last = None;
+    /// 
+    ///     Gob::extract_all_with_progress(&mut Cursor::new(gob.as_bytes()?), &path, |progress| {
+    ///         last = Some(progress);
+    ///     })?;
+    /// 
+    ///     let last = last.unwrap();
+    /// 
+    ///     assert_eq!((last.files_done, last.files_total), (2, 2));
+    /// 
+    ///     assert_eq!((last.bytes_done, last.bytes_total), (14, 14));
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extract_all_with_progress<R: Read + Seek, F: FnMut(Progress)>(
+        reader: &mut R,
+        path: &Path,
+        mut progress: F,
+    ) -> Result<(), GobError> {
+        let file_definitions = Self::file_table(reader)?;
+
+        for file_definition in &file_definitions {
+            Self::check_extractable(&file_definition.filepath)?;
+        }
+
+        let mut current = Progress {
+            files_done: 0,
+            files_total: file_definitions.len(),
+            bytes_done: 0,
+            bytes_total: file_definitions.iter().map(|file_definition| file_definition.size).sum(),
+        };
+
+        for file_definition in &file_definitions {
+            let path = path.join(&file_definition.filepath);
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            reader.seek(SeekFrom::Start(file_definition.offset))?;
+
+            let mut file = BufWriter::new(fs::File::create(&path)?);
+
+            io::copy(&mut reader.by_ref().take(file_definition.size), &mut file)?;
+
+            file.flush()?;
+
+            current.files_done += 1;
+
+            current.bytes_done += file_definition.size;
+
+            progress(current);
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a file at a given filepath can be extracted into a
+    /// directory, i.e. whether the filepath stays within the directory and
+    /// consists of components legal as filenames on the current platform.
+    fn check_extractable(filepath: &Path) -> Result<(), GobError> {
+        if !path::is_contained(filepath) {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Filepath escapes the directory: {}", filepath.display())).into());
+        }
+
+        if !filepath.iter().all(path::is_legal_component) {
+            return Err(GobError::IllegalPathComponent { path: filepath.to_path_buf() });
+        }
+
+        Ok(())
+    }
+
+    /// Encodes a filepath for the filepath field of a file definition, checking
+    /// that it is safe to extract, representable in a given encoding, free of
+    /// null bytes and short enough for the field of a given format.
+    pub(crate) fn encode_filepath(filepath: &Path, encoding: FilenameEncoding, format: GobFormat) -> Result<Vec<u8>, GobError> {
+        if !path::is_contained(filepath) {
+            return Err(GobError::UnsafePath(filepath.to_path_buf()));
+        }
+
+        let filepath_bytes = match path::to_gob_bytes(filepath, encoding) {
+            Some(filepath_bytes) => filepath_bytes,
+            None => {
+                return Err(GobError::UnrepresentableFilepath(filepath.to_path_buf()));
+            }
+        };
+
+        if filepath_bytes.contains(&0) {
+            return Err(GobError::NullInPath(filepath.to_path_buf()));
+        }
+
+        let filepath_len = format.filepath_len();
+
+        if filepath_bytes.len() >= filepath_len {
+            return Err(GobError::FilepathTooLong {
+                path: filepath.to_path_buf(),
+                len: filepath_bytes.len(),
+                max: filepath_len - 1,
+            });
+        }
+
+        Ok(filepath_bytes)
+    }
+
+    pub(crate) const VERSION: u32 = 0x14;
+
+    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let gob = Gob::from_file(Path::new("/path/to/gob.GOB"))?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, GobError> {
+        Self::from_file_with_options(path, ParseOptions::default())
+    }
+
+    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file,
+    /// parsing it according to given [`ParseOptions`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::{core::Gob, format::FilenameEncoding, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let options = ParseOptions::new().filename_encoding(FilenameEncoding::Cp437);
+    /// 
+    ///     let gob = Gob::from_file_with_options(Path::new("/path/to/gob.GOB"), options)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file_with_options(path: &Path, options: ParseOptions) -> Result<Self, GobError> {
+        if !path.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        let mut file = fs::File::open(path)?;
+
+        Self::from_reader_with_options(&mut file, options)
+    }
+
+    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file,
+    /// calling a given closure with the [`Progress`] after each file is read.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_from_file_with_progress.gob");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let mut reported = Vec::new();
+    /// 
+    ///     let parsed = Gob::from_file_with_progress(&path, |progress| {
+    ///         reported.push((progress.files_done, progress.files_total));
+    ///     })?;
+    /// 
+    ///     assert!(parsed == gob);
+    /// 
+    ///     assert_eq!(reported, [(1, 2), (2, 2)]);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file_with_progress<F: FnMut(Progress)>(path: &Path, progress: F) -> Result<Self, GobError> {
+        if !path.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        let mut file = io::BufReader::new(fs::File::open(path)?);
+
+        Self::parse_with_progress(&mut file, ParseOptions::default(), progress)
+    }
+
+    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file
+    /// together with a [`ParseReport`] on what was observed while parsing it.
+    /// See [`Gob::from_reader_verbose`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let (gob, report) = Gob::from_file_verbose(Path::new("/path/to/gob.GOB"))?;
+    /// 
+    ///     println!("{} files, {} duplicates", report.file_count, report.duplicate_count);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file_verbose(path: &Path) -> Result<(Self, ParseReport), GobError> {
+        if !path.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        let mut file = io::BufReader::new(fs::File::open(path)?);
+
+        Self::from_reader_verbose(&mut file)
+    }
+
+    /// Creates a new [`Gob`] object from a given [`Path`] to a GOB archive file
+    /// without blocking the asynchronous runtime.
+    /// 
+    /// The file is read into memory using [`tokio::fs`] and then parsed like
+    /// by [`Gob::from_bytes`]. Requires the `tokio` feature.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// async fn load() -> std::io::Result<Gob> {
+    ///     let gob = Gob::from_file_async(Path::new("/path/to/gob.GOB")).await?;
+    /// 
+    ///     Ok(gob)
+    /// }
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn from_file_async(path: &Path) -> Result<Self, GobError> {
+        use tokio::io::AsyncReadExt;
+
+        if !tokio::fs::metadata(path).await?.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        let mut file = tokio::fs::File::open(path).await?;
+
+        let mut data: Vec<u8> = Vec::new();
+
+        file.read_to_end(&mut data).await?;
+
+        Self::from_bytes(&data)
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of a
+    /// ZIP archive, converting its files into files of the GOB archive.
+    /// 
+    /// The filepaths are normalized like with [`path::normalize`], keeping
+    /// their case. Directories are skipped. Fails with an error of kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) if the ZIP archive is
+    /// invalid or a filepath escapes the archive, e.g. through `..` components.
+    /// 
+    /// Requires the `zip` feature.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let mut zip = Cursor::new(Vec::new());
+    /// 
+    ///     gob.to_zip(&mut zip)?;
+    /// 
+    ///     assert_eq!(Gob::from_zip(&mut zip)?, gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "zip")]
+    pub fn from_zip<R: Read + Seek>(reader: &mut R) -> Result<Self, GobError> {
+        let mut archive = zip::ZipArchive::new(reader).map_err(io::Error::from)?;
+
+        let mut gob = Self::with_capacity(archive.len());
+
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).map_err(io::Error::from)?;
+
+            if file.is_dir() {
+                continue;
+            }
+
+            let name = file.name().map_err(io::Error::from)?.into_owned();
+
+            let filepath = Self::check_contained(Path::new(&name))?;
+
+            let mut data: Vec<u8> = Vec::with_capacity(file.size() as usize);
+
+            file.read_to_end(&mut data)?;
+
+            gob.files.insert(filepath, data);
+        }
+
+        Ok(gob)
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of a
+    /// tar archive, converting its regular files into files of the GOB archive.
+    /// 
+    /// The filepaths are normalized like with [`path::normalize`], keeping
+    /// their case. Directories, links and other special entries are skipped.
+    /// Fails with an error of kind [`InvalidData`](std::io::ErrorKind::InvalidData)
+    /// if a filepath escapes the archive, e.g. through `..` components.
+    /// 
+    /// Requires the `tar` feature.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let mut tar: Vec<u8> = Vec::new();
+    /// 
+    ///     gob.to_tar(&mut tar)?;
+    /// 
+    ///     assert_eq!(Gob::from_tar(&mut tar.as_slice())?, gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "tar")]
+    pub fn from_tar<R: Read>(reader: &mut R) -> Result<Self, GobError> {
+        let mut archive = tar::Archive::new(reader);
+
+        let mut gob = Self::new();
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let filepath = Self::check_contained(&entry.path()?)?;
+
+            let mut data: Vec<u8> = Vec::with_capacity(entry.size() as usize);
+
+            entry.read_to_end(&mut data)?;
+
+            gob.files.insert(filepath, data);
+        }
+
+        Ok(gob)
+    }
+
+    /// Normalizes a filepath of a file converted from another kind of archive,
+    /// checking that it stays within the archive.
+    #[cfg(any(feature = "zip", feature = "tar"))]
+    fn check_contained(filepath: &Path) -> Result<PathBuf, GobError> {
+        let filepath = path::normalize(filepath);
+
+        if path::is_contained(&filepath) {
+            Ok(filepath)
+        } else {
+            Err(Error::new(ErrorKind::InvalidData, format!("Filepath escapes the archive: {}", filepath.display())).into())
+        }
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of
+    /// a GOB archive, e.g. a [`fs::File`] or a [`std::io::Cursor`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut reader = Cursor::new(gob.as_bytes()?);
+    /// 
+    ///     let gob = Gob::from_reader(&mut reader)?;
+    /// 
+    ///     assert_eq!(gob.files.get(&PathBuf::from("foo.bar")), Some(&b"foobar".to_vec()));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Parses a file and an in-memory copy of it identically:
+    /// 
+    /// ```
+    /// use std::{fs, io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_from_reader_file.GOB");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let from_file = Gob::from_reader(&mut fs::File::open(&path)?)?;
+    /// 
+    ///     let from_memory = Gob::from_reader(&mut Cursor::new(fs::read(&path)?))?;
+    /// 
+    ///     assert_eq!(from_file.files, from_memory.files);
+    /// 
+    ///     fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Rejects files whose data extends beyond the end of the archive:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// let mut data = gob.as_bytes().unwrap();
+    /// 
+    /// data[20..24].copy_from_slice(&7u32.to_le_bytes());
+    /// 
+    /// assert!(matches!(
+    ///     Gob::from_reader(&mut Cursor::new(data)),
+    ///     Err(GobError::FileOutOfBounds(filepath)) if filepath == PathBuf::from("foo.bar"),
+    /// ));
+    /// ```
+    /// 
+    /// Rejects file counts exceeding the file definitions the archive can hold
+    /// before reading any of them:
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut data = Gob::new().as_bytes().unwrap();
+    /// 
+    /// data[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+    /// 
+    /// assert!(matches!(
+    ///     Gob::from_reader(&mut Cursor::new(data)),
+    ///     Err(GobError::Truncated { field: "file definitions" }),
+    /// ));
+    /// ```
+    /// 
+    /// Parses archives placing the file definitions after the data of the files,
+    /// as the offset stored in the header is followed:
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut data = b"GOB \x14\x00\x00\x00".to_vec();
+    /// 
+    ///     // Offset of the file count, following the data.
+    ///     data.extend(18u32.to_le_bytes());
+    /// 
+    ///     data.extend(b"foobar");
+    /// 
+    ///     data.extend(1u32.to_le_bytes());
+    /// 
+    ///     data.extend(12u32.to_le_bytes());
+    /// 
+    ///     data.extend(6u32.to_le_bytes());
+    /// 
+    ///     let mut filepath = [0; 128];
+    /// 
+    ///     filepath[..7].copy_from_slice(b"foo.bar");
+    /// 
+    ///     data.extend(filepath);
+    /// 
+    ///     let gob = Gob::from_reader(&mut Cursor::new(data))?;
+    /// 
+    ///     assert_eq!(gob.get("foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader<R: Read + Seek>(reader: &mut R) -> Result<Self, GobError> {
+        Self::from_reader_with_options(reader, ParseOptions::default())
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of
+    /// a GOB archive, parsing it according to given [`ParseOptions`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, format::FilenameEncoding, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let data = Gob::new().as_bytes()?;
+    /// 
+    ///     let options = ParseOptions::new().filename_encoding(FilenameEncoding::Latin1);
+    /// 
+    ///     let gob = Gob::from_reader_with_options(&mut Cursor::new(data), options)?;
+    /// 
+    ///     assert_eq!(gob.filename_encoding(), FilenameEncoding::Latin1);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Self, GobError> {
+        Self::parse_with_progress(reader, options, |_| {})
+    }
+
+    fn parse_with_progress<R: Read + Seek, F: FnMut(Progress)>(
+        reader: &mut R,
+        options: ParseOptions,
+        progress: F,
+    ) -> Result<Self, GobError> {
+        let table = Self::read_file_definitions(reader, &options)?;
+
+        if options.mode == ParseMode::Strict {
+            Self::parse_warnings(&options, &table)?;
+        }
+
+        Self::from_table(reader, options, table, progress)
+    }
+
+    /// Reads the data of the files of an archive whose header and file
+    /// definitions were already parsed.
+    fn from_table<R: Read + Seek, F: FnMut(Progress)>(
+        reader: &mut R,
+        options: ParseOptions,
+        table: ParsedTable,
+        mut progress: F,
+    ) -> Result<Self, GobError> {
+        let ParsedTable {
+            format,
+            version,
+            file_definitions,
+            ..
+        } = table;
+
+        let mut files = GobMap::with_capacity(file_definitions.len());
+
+        // The file definitions whose data is kept, in case the layout is preserved.
+        let mut kept: IndexMap<PathBuf, FileDefinition> = IndexMap::new();
+
+        let mut current = Progress {
+            files_done: 0,
+            files_total: file_definitions.len(),
+            bytes_done: 0,
+            bytes_total: file_definitions.iter().map(|file_definition| file_definition.size).sum(),
+        };
+
+        for file_definition in file_definitions {
+            current.files_done += 1;
+
+            current.bytes_done += file_definition.size;
+
+            if options.duplicates == DuplicatePolicy::KeepFirst && files.contains_key(&file_definition.filepath) {
+                progress(current);
+
+                continue;
+            }
+
+            let data = file_definition.read_data(reader)?;
+
+            #[cfg(feature = "compression")]
+            let data = options.compression.decompress(data)?;
+
+            files.insert(file_definition.filepath.clone(), data);
+
+            if options.preserve_layout {
+                kept.insert(file_definition.filepath.clone(), file_definition);
+            }
+
+            progress(current);
+        }
+
+        #[cfg(feature = "compression")]
+        let preserve_layout = options.preserve_layout && options.compression == CompressionCodec::None;
+
+        #[cfg(not(feature = "compression"))]
+        let preserve_layout = options.preserve_layout;
+
+        let raw_layout = if preserve_layout {
+            Some(RawLayout::capture(reader, format, kept.into_values().collect())?)
+        } else {
+            None
+        };
+
+        let mut gob = Self::from(files);
+
+        gob.format = format;
+
+        gob.version = version;
+
+        gob.filename_encoding = options.filename_encoding;
+
+        gob.raw_layout = raw_layout;
+
+        #[cfg(feature = "compression")]
+        {
+            gob.compression = options.compression;
+        }
+
+        Ok(gob)
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of
+    /// a GOB archive, parsing it according to given [`ParseOptions`], together
+    /// with the [`ParseWarning`]s on what makes the archive non-canonical.
+    /// 
+    /// In strict mode (see [`ParseOptions::mode`]), parsing fails on the first
+    /// warning instead, so none are returned.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::{Gob, ParseWarning}, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     gob.set_data_alignment(4);
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let (parsed, warnings) = Gob::from_reader_with_warnings(&mut Cursor::new(data), ParseOptions::new())?;
+    /// 
+    ///     assert!(parsed.content_eq(&gob));
+    /// 
+    ///     assert_eq!(warnings, [
+    ///         ParseWarning::Gap { offset: 16 + 2 * 136 + 6, size: 2 },
+    ///         ParseWarning::UnsortedTable,
+    ///     ]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Canonical archives with nested files parse without warnings, also in
+    /// strict mode:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, options::{ParseMode, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission").join("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let (_, warnings) = Gob::from_reader_with_warnings(&mut Cursor::new(&data), ParseOptions::new())?;
+    /// 
+    ///     assert!(warnings.is_empty());
+    /// 
+    ///     let options = ParseOptions::new().mode(ParseMode::Strict);
+    /// 
+    ///     assert!(Gob::from_reader_with_options(&mut Cursor::new(&data), options)? == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_with_warnings<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<(Self, Vec<ParseWarning>), GobError> {
+        let table = Self::read_file_definitions(reader, &options)?;
+
+        let warnings = Self::parse_warnings(&options, &table)?;
+
+        let gob = Self::from_table(reader, options, table, |_| {})?;
+
+        Ok((gob, warnings))
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of
+    /// a GOB archive embedded in a larger file, starting at a given offset.
+    /// 
+    /// The offsets within the header and the file definitions are taken to be
+    /// relative to the start of the archive.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = b"BUNDLE".to_vec();
+    /// 
+    ///     data.extend(gob.as_bytes()?);
+    /// 
+    ///     data.extend(b"TRAILER");
+    /// 
+    ///     let parsed = Gob::from_reader_at(&mut Cursor::new(data), 6)?;
+    /// 
+    ///     assert!(parsed == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_at<R: Read + Seek>(reader: &mut R, start: u64) -> Result<Self, GobError> {
+        Self::from_reader(&mut byte::Embedded::new(reader, start))
+    }
+
+    /// Creates a new [`Gob`] object from the data of a GOB archive in memory.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let parsed = Gob::from_bytes(&gob.as_bytes()?)?;
+    /// 
+    ///     assert_eq!(parsed.files, gob.files);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Fails gracefully on archives truncated at any field, reporting the field
+    /// which could not be read completely:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let fields = [
+    ///         (0, "signature"),
+    ///         (3, "signature"),
+    ///         (4, "version"),
+    ///         (8, "body offset"),
+    ///         (12, "file count"),
+    ///         (16, "file definitions"),
+    ///         (151, "file definitions"),
+    ///     ];
+    /// 
+    ///     for (len, field) in fields {
+    ///         assert!(matches!(
+    ///             Gob::from_bytes(&data[..len]),
+    ///             Err(GobError::Truncated { field: truncated }) if truncated == field,
+    ///         ));
+    ///     }
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::from_bytes(&data[..data.len() - 1]),
+    ///         Err(GobError::FileOutOfBounds(_)),
+    ///     ));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Ends each filepath at the first null byte in its filepath field, ignoring
+    /// any bytes following it:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar!baz"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Replace the "!" within the filepath field with a null byte.
+    ///     data[24 + 7] = 0;
+    /// 
+    ///     let parsed = Gob::from_bytes(&data)?;
+    /// 
+    ///     assert_eq!(parsed.files.keys().collect::<Vec<_>>(), [&PathBuf::from("foo.bar")]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Result<Self, GobError> {
+        Self::from_reader(&mut Cursor::new(data))
+    }
+
+    /// Creates a new [`GobView`] object from the data of a GOB archive in memory,
+    /// borrowing the data of the files from it instead of copying it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let view = Gob::from_bytes_borrowed(&data)?;
+    /// 
+    ///     assert_eq!(view.get("foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     assert_eq!(view.into_owned(), gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes_borrowed(data: &[u8]) -> Result<GobView<'_>, GobError> {
+        GobView::with_options(data, ParseOptions::default())
+    }
+
+    /// Extracts the data of a single file at a given path from a given reader
+    /// providing the data of a GOB archive.
+    /// 
+    /// Only the header and the file definitions are parsed, and only the data
+    /// of the requested file is read. Returns `None` if the archive does not
+    /// contain the file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::{Path, PathBuf}};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let mut reader = Cursor::new(gob.as_bytes()?);
+    /// 
+    ///     let data = Gob::extract_file(&mut reader, Path::new("fizz.buzz"))?;
+    /// 
+    ///     assert_eq!(data, Some(b"fizzbuzz".to_vec()));
+    /// 
+    ///     let data = Gob::extract_file(&mut reader, Path::new("foo.baz"))?;
+    /// 
+    ///     assert_eq!(data, None);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extract_file<R: Read + Seek>(reader: &mut R, path: &Path) -> Result<Option<Vec<u8>>, GobError> {
+        GobIndex::new(reader)?.read(path)
+    }
+
+    /// Reads the file definitions of a GOB archive from a given reader, in the
+    /// order they are stored in, without reading the data of the files.
+    /// 
+    /// File definitions sharing the same filepath are all included.
+    /// 
+    /// # Examples
+    /// 
+    /// Inspects the layout of a GOB archive:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let table = Gob::file_table(&mut Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     let layout: Vec<(u64, u64)> = table
+    ///         .iter()
+    ///         .map(|file_definition| (file_definition.offset, file_definition.size))
+    ///         .collect();
+    /// 
+    ///     assert_eq!(layout, [(16 + 2 * 136, 6), (16 + 2 * 136 + 6, 8)]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn file_table<R: Read + Seek>(reader: &mut R) -> Result<Vec<FileDefinition>, GobError> {
+        let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+
+        Ok(Self::read_file_definitions(reader, &options)?.file_definitions)
+    }
+
+    /// Reads the file definitions of a GOB archive file at a given [`Path`], like
+    /// [`Gob::file_table`], without reading the data of the files.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_definitions.gob");
+    /// 
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let definitions = Gob::definitions(&path)?;
+    /// 
+    ///     assert_eq!(definitions.len(), 1);
+    /// 
+    ///     assert_eq!(definitions[0].filepath, Path::new("foo.bar"));
+    /// 
+    ///     assert_eq!((definitions[0].offset, definitions[0].size), (16 + 136, 6));
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn definitions(path: &Path) -> Result<Vec<FileDefinition>, GobError> {
+        let mut reader = io::BufReader::new(fs::File::open(path)?);
+
+        Self::file_table(&mut reader)
+    }
+
+    /// Lists the filepaths of the files of a GOB archive from a given reader,
+    /// in the order they are stored in, without reading the data of the files.
+    /// 
+    /// Filepaths occurring more than once are listed each time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission/foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let filepaths = Gob::list(&mut Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     assert_eq!(filepaths, [PathBuf::from("mission/foo.bar"), PathBuf::from("fizz.buzz")]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list<R: Read + Seek>(reader: &mut R) -> Result<Vec<PathBuf>, GobError> {
+        let file_definitions = Self::file_table(reader)?;
+
+        Ok(file_definitions.into_iter().map(|file_definition| file_definition.filepath).collect())
+    }
+
+    /// Verifies that the data of every file of a GOB archive from a given reader
+    /// can be read, reading it without holding on to it.
+    /// 
+    /// Fails on the first file whose data cannot be read completely, naming its
+    /// filepath.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     Gob::verify(&mut Cursor::new(&data))?;
+    /// 
+    ///     data.pop();
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::verify(&mut Cursor::new(&data)),
+    ///         Err(GobError::FileOutOfBounds(filepath)) if filepath == PathBuf::from("foo.bar"),
+    ///     ));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn verify<R: Read + Seek>(reader: &mut R) -> Result<(), GobError> {
+        let file_definitions = Self::file_table(reader)?;
+
+        for file_definition in file_definitions {
+            let result = reader
+                .seek(SeekFrom::Start(file_definition.offset))
+                .and_then(|_| io::copy(&mut reader.by_ref().take(file_definition.size), &mut io::sink()));
+
+            match result {
+                Ok(size) if size == file_definition.size => {}
+                Ok(_) => {
+                    return Err(GobError::FileOutOfBounds(file_definition.filepath));
+                }
+                Err(error) => {
+                    return Err(Error::new(error.kind(), format!("Unable to read file: {}: {error}", file_definition.filepath.display())).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Determines details about the layout of a GOB archive from a given reader,
+    /// without reading the data of the files.
+    /// 
+    /// # Examples
+    /// 
+    /// Detects data trailing the archive, which is ignored when parsing:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     let details = Gob::parse_details(&mut Cursor::new(&data))?;
+    /// 
+    ///     assert!(!details.has_trailing_data);
+    /// 
+    ///     data.extend(b"garbage");
+    /// 
+    ///     let details = Gob::parse_details(&mut Cursor::new(&data))?;
+    /// 
+    ///     assert!(details.has_trailing_data);
+    /// 
+    ///     assert_eq!(details.length - details.end, 7);
+    /// 
+    ///     assert!(Gob::from_bytes(&data)? == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn parse_details<R: Read + Seek>(reader: &mut R) -> Result<ParseDetails, GobError> {
+        let (format, length, regions) = Self::read_layout(reader)?;
+
+        let end = regions.iter().map(|&(_, end)| end).max().unwrap_or(0);
+
+        Ok(ParseDetails {
+            format,
+            length,
+            end,
+            has_trailing_data: length > end,
+        })
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of a
+    /// GOB archive together with a [`ParseReport`] on what was observed while
+    /// parsing it, such as duplicate filepaths and unreferenced bytes.
+    /// 
+    /// Files sharing the same filepath are resolved by keeping the one defined
+    /// last, like the games using GOB archives do, instead of failing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     gob.set_data_alignment(4);
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     data.extend(b"garbage");
+    /// 
+    ///     let (parsed, report) = Gob::from_reader_verbose(&mut Cursor::new(&data))?;
+    /// 
+    ///     assert_eq!(parsed, gob);
+    /// 
+    ///     assert_eq!(report.format, GobFormat::JediKnight);
+    /// 
+    ///     assert_eq!(report.version, 0x14);
+    /// 
+    ///     assert_eq!(report.file_count, 2);
+    /// 
+    ///     assert!(report.has_directories);
+    /// 
+    ///     assert_eq!(report.duplicate_count, 0);
+    /// 
+    ///     assert_eq!(report.gap_size, 2 + 7);
+    /// 
+    ///     assert!(report.has_trailing_data);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_verbose<R: Read + Seek>(reader: &mut R) -> Result<(Self, ParseReport), GobError> {
+        Self::from_reader_verbose_with_options(reader, ParseOptions::new().duplicates(DuplicatePolicy::KeepLast))
+    }
+
+    /// Creates a new [`Gob`] object from a given reader providing the data of a
+    /// GOB archive, parsing it according to given [`ParseOptions`], together
+    /// with a [`ParseReport`] like [`Gob::from_reader_verbose`].
+    /// 
+    /// Duplicate filepaths are only counted if [`ParseOptions::duplicates`] lets
+    /// parsing get past them.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, format::FilenameEncoding, options::{DuplicatePolicy, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_filename_encoding(FilenameEncoding::Latin1);
+    /// 
+    ///     gob.files.insert(PathBuf::from("caf\u{e9}.txt"), b"foobar".to_vec());
+    /// 
+    ///     let options = ParseOptions::new()
+    ///         .filename_encoding(FilenameEncoding::Latin1)
+    ///         .duplicates(DuplicatePolicy::KeepFirst);
+    /// 
+    ///     let (parsed, report) = Gob::from_reader_verbose_with_options(&mut Cursor::new(gob.as_bytes()?), options)?;
+    /// 
+    ///     assert_eq!(parsed.get("caf\u{e9}.txt"), Some(&b"foobar"[..]));
+    /// 
+    ///     assert_eq!(report.file_count, 1);
+    /// 
+    ///     assert_eq!(report.gap_size, 0);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_reader_verbose_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<(Self, ParseReport), GobError> {
+        let table = Self::read_file_definitions(reader, &options)?;
+
+        if options.mode == ParseMode::Strict {
+            Self::parse_warnings(&options, &table)?;
+        }
+
+        let mut filepaths = HashSet::new();
+
+        let duplicate_count = table
+            .file_definitions
+            .iter()
+            .filter(|file_definition| !filepaths.insert(&file_definition.filepath))
+            .count();
+
+        let gaps = Self::gaps(Self::regions(&table), table.length);
+
+        let report = ParseReport {
+            format: table.format,
+            version: table.version,
+            file_count: table.file_definitions.len(),
+            has_directories: table
+                .file_definitions
+                .iter()
+                .any(|file_definition| file_definition.filepath.iter().count() > 1),
+            duplicate_count,
+            gap_size: gaps.iter().map(|gap| gap.size).sum(),
+            has_trailing_data: gaps.last().is_some_and(|gap| gap.offset + gap.size == table.length),
+        };
+
+        let gob = Self::from_table(reader, options, table, |_| {})?;
+
+        Ok((gob, report))
+    }
+
+    /// Determines how the space of a GOB archive from a given reader is used,
+    /// without reading the data of the files.
+    /// 
+    /// Gaps are the ranges of bytes referenced neither by the header, the file
+    /// definitions nor the data of any file, such as padding between files or
+    /// data left behind by appending (see [`Gob::compact_file`]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::{Gap, Gob};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_data_alignment(8);
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let report = Gob::layout_report(&mut Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     assert_eq!(report.data_size, 14);
+    /// 
+    ///     assert_eq!(report.overhead, 12 + 4 + 2 * 136);
+    /// 
+    ///     assert_eq!(report.gap_size, 2);
+    /// 
+    ///     assert_eq!(report.largest_gap, Some(Gap { offset: 294, size: 2 }));
+    /// 
+    ///     assert_eq!(report.total_size, report.data_size + report.overhead + report.gap_size);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn layout_report<R: Read + Seek>(reader: &mut R) -> Result<LayoutReport, GobError> {
+        let (_, length, regions) = Self::read_layout(reader)?;
+
+        let overhead = (regions[0].1 - regions[0].0) + (regions[1].1 - regions[1].0);
+
+        let data_size = regions[2..].iter().map(|&(start, end)| end - start).sum();
+
+        let gaps = Self::gaps(regions, length);
+
+        let gap_size = gaps.iter().map(|gap| gap.size).sum();
+
+        // Searched in reverse to keep the first of equally large gaps.
+        let largest_gap = gaps.iter().rev().max_by_key(|gap| gap.size).copied();
+
+        Ok(LayoutReport {
+            total_size: length,
+            data_size,
+            overhead,
+            gap_size,
+            largest_gap,
+        })
+    }
+
+    /// Finds the ranges of bytes of a GOB archive from a given reader which are
+    /// referenced by the data of more than one file, without reading the data
+    /// of the files.
+    /// 
+    /// Overlapping data is either shared deliberately (see
+    /// [`Gob::set_deduplicating`]) or indicates a corrupt archive. Files without
+    /// data overlap nothing. The overlaps are ordered by their offsets.
+    /// Together with [`Gob::layout_report`], this tells whether an archive is
+    /// tightly packed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::{Gob, Overlap};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     assert!(Gob::overlaps(&mut Cursor::new(&data))?.is_empty());
+    /// 
+    ///     // Move the data of the second file back by two bytes.
+    ///     let offset = 16 + 2 * 136 + 4;
+    /// 
+    ///     data[16 + 136..16 + 136 + 4].copy_from_slice(&(offset as u32).to_le_bytes());
+    /// 
+    ///     assert_eq!(Gob::overlaps(&mut Cursor::new(&data))?, [Overlap {
+    ///         offset,
+    ///         size: 2,
+    ///         filepaths: [PathBuf::from("foo.bar"), PathBuf::from("fizz.buzz")],
+    ///     }]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn overlaps<R: Read + Seek>(reader: &mut R) -> Result<Vec<Overlap>, GobError> {
+        let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+
+        let mut file_definitions = Self::read_file_definitions(reader, &options)?.file_definitions;
+
+        file_definitions.retain(|file_definition| file_definition.size > 0);
+
+        file_definitions.sort_by_key(|file_definition| file_definition.offset);
+
+        let mut overlaps: Vec<Overlap> = Vec::new();
+
+        for (i, first) in file_definitions.iter().enumerate() {
+            let first_end = first.offset + first.size;
+
+            for second in file_definitions[i + 1..].iter().take_while(|second| second.offset < first_end) {
+                overlaps.push(Overlap {
+                    offset: second.offset,
+                    size: first_end.min(second.offset + second.size) - second.offset,
+                    filepaths: [first.filepath.clone(), second.filepath.clone()],
+                });
+            }
+        }
+
+        overlaps.sort_by_key(|overlap| overlap.offset);
+
+        Ok(overlaps)
+    }
+
+    /// Parses the header and the file definitions of a GOB archive, returning
+    /// the variant of the format, the length of the archive, and the ranges of
+    /// bytes occupied by, in order, the header, the file definitions including
+    /// the file count, and the data of each file.
+    fn read_layout<R: Read + Seek>(reader: &mut R) -> Result<(GobFormat, u64, Vec<Region>), GobError> {
+        let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+
+        let table = Self::read_file_definitions(reader, &options)?;
+
+        Ok((table.format, table.length, Self::regions(&table)))
+    }
+
+    /// Gets the regions of an archive occupied by the header, the file
+    /// definitions and the data of each file, in this order.
+    fn regions(table: &ParsedTable) -> Vec<Region> {
+        let header_end: u64 = match table.format {
+            GobFormat::DarkForces => 8,
+            GobFormat::JediKnight => 12,
+        };
+
+        let definitions_len = (8 + table.format.filepath_len() as u64) * table.file_definitions.len() as u64;
+
+        let mut regions = vec![(0, header_end), (table.body_offset, table.body_offset + 4 + definitions_len)];
+
+        regions.extend(
+            table
+                .file_definitions
+                .iter()
+                .map(|file_definition| (file_definition.offset, file_definition.offset + file_definition.size)),
+        );
+
+        regions
+    }
+
+    /// Finds the ranges of bytes of an archive of a given length not covered by
+    /// any of given regions, ordered by their offsets.
+    fn gaps(mut regions: Vec<Region>, length: u64) -> Vec<Gap> {
+        regions.sort_unstable();
+
+        let mut gaps: Vec<Gap> = Vec::new();
+
+        let mut position = 0;
+
+        for (start, end) in regions.into_iter().chain([(length, length)]) {
+            if start > position {
+                gaps.push(Gap {
+                    offset: position,
+                    size: start - position,
+                });
+            }
+
+            position = position.max(end);
+        }
+
+        gaps
+    }
+
+    /// Collects what makes an archive non-canonical, failing on the first
+    /// [`ParseWarning`] instead if parsing in strict mode.
+    fn parse_warnings(options: &ParseOptions, table: &ParsedTable) -> Result<Vec<ParseWarning>, GobError> {
+        let file_definitions = &table.file_definitions;
+
+        let mut warnings: Vec<ParseWarning> = Self::gaps(Self::regions(table), table.length)
+            .into_iter()
+            .map(|gap| match gap.offset + gap.size == table.length {
+                true => ParseWarning::TrailingData { size: gap.size },
+                false => ParseWarning::Gap {
+                    offset: gap.offset,
+                    size: gap.size,
+                },
+            })
+            .collect();
+
+        let sorted = file_definitions
+            .windows(2)
+            .all(|pair| path::sort_key(&pair[0].filepath) <= path::sort_key(&pair[1].filepath));
+
+        if !sorted {
+            warnings.push(ParseWarning::UnsortedTable);
+        }
+
+        for filepath in &table.mixed_separators {
+            warnings.push(ParseWarning::MixedSeparators(filepath.clone()));
+        }
+
+        if options.mode == ParseMode::Strict {
+            if let Some(warning) = warnings.into_iter().next() {
+                return Err(GobError::NonCanonical(warning));
+            }
+
+            return Ok(Vec::new());
+        }
+
+        Ok(warnings)
+    }
+
+    /// Reads the number of files of a GOB archive from a given reader, reading
+    /// only the header and the file count.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     assert_eq!(Gob::entry_count(&mut Cursor::new(gob.as_bytes()?))?, 2);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn entry_count<R: Read + Seek>(reader: &mut R) -> Result<u32, GobError> {
+        Self::read_header(reader, &ParseOptions::default())?;
+
+        Ok(u32::from_le_bytes(byte::slice!(reader, 4, "file count")))
+    }
+
+    /// Detects the variant of the GOB format a reader holds from the header,
+    /// without parsing the file definitions, for telling GOB archives apart
+    /// from other files cheaply.
+    /// 
+    /// Returns `None` if the reader does not start with a complete header of a
+    /// supported GOB archive, i.e. if the signature or the version is not
+    /// recognized or the reader ends within the header. Only I/O errors are
+    /// returned as errors.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.set_format(GobFormat::DarkForces);
+    /// 
+    ///     assert_eq!(Gob::probe(&mut Cursor::new(gob.as_bytes()?))?, Some(GobFormat::DarkForces));
+    /// 
+    ///     assert_eq!(Gob::probe(&mut Cursor::new(b"PK\x03\x04"))?, None);
+    /// 
+    ///     assert_eq!(Gob::probe(&mut Cursor::new(b"GOB "))?, None);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn probe<R: Read + Seek>(reader: &mut R) -> io::Result<Option<GobFormat>> {
+        match Self::read_header(reader, &ParseOptions::default()) {
+            Ok((format, _, _)) => Ok(Some(format)),
+            Err(GobError::BadSignature | GobError::BadVersion(_) | GobError::Truncated { .. }) => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Parses the header of a GOB archive, detecting the variant of the format
+    /// from the signature, and seeks to the file count following the offset
+    /// stored in the header.
+    /// 
+    /// Only the header is assumed to be at a fixed location: the signature is
+    /// followed, for Jedi Knight archives, by the version and then by the offset
+    /// of the file count. The file count is assumed to be followed immediately by
+    /// the file definitions, which may be located anywhere in the archive, just
+    /// like the data of the files, whose offsets are read from the definitions.
+    fn read_header<R: Read + Seek>(reader: &mut R, options: &ParseOptions) -> Result<(GobFormat, u32, u64), GobError> {
+        reader.seek(SeekFrom::Start(0))?;
+
+        let signature = &byte::slice!(reader, 4, "signature");
+
+        let format = match GobFormat::from_signature(signature) {
+            Some(format) => format,
+            None => {
+                return Err(GobError::BadSignature);
+            }
+        };
+
+        let version = match format {
+            GobFormat::DarkForces => Self::VERSION,
+            GobFormat::JediKnight => u32::from_le_bytes(byte::slice!(reader, 4, "version")),
+        };
+
+        if format == GobFormat::JediKnight && !options.accepts_version(version) {
+            return Err(GobError::BadVersion(version));
+        }
+
+        let body_offset = u32::from_le_bytes(byte::slice!(reader, 4, "body offset")) as u64;
+
+        reader.seek(SeekFrom::Start(body_offset))?;
+
+        Ok((format, version, body_offset))
+    }
+
+    /// Parses the header and the file definitions of a GOB archive, detecting
+    /// the variant of the format from the signature.
+    pub(crate) fn read_file_definitions<R: Read + Seek>(reader: &mut R, options: &ParseOptions) -> Result<ParsedTable, GobError> {
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        let (format, version, body_offset) = Self::read_header(reader, options)?;
+
+        let file_count = u32::from_le_bytes(byte::slice!(reader, 4, "file count"));
+
+        let filepath_len = format.filepath_len();
+
+        // Reject counts the archive cannot hold before reading any definitions.
+        let definitions_len = (8 + filepath_len as u64) * file_count as u64;
+
+        if definitions_len > length.saturating_sub(body_offset + 4) {
+            return Err(GobError::Truncated { field: "file definitions" });
+        }
+
+        let mut file_definitions: Vec<FileDefinition> = Vec::with_capacity(file_count as usize);
+
+        let mut filepaths = HashSet::new();
+
+        let mut mixed_separators: Vec<PathBuf> = Vec::new();
+
+        for _ in 0..file_count {
+            let offset = u32::from_le_bytes(byte::slice!(reader, 4, "offset of file definition")) as u64;
+
+            let size = u32::from_le_bytes(byte::slice!(reader, 4, "size of file definition")) as u64;
+
+            let mut filepath_bytes = vec![0; filepath_len];
+
+            byte::read_exact(reader, &mut filepath_bytes, "filepath of file definition")?;
+
+            let filepath_end = filepath_bytes.iter().position(|&n| n == 0).unwrap_or(filepath_len);
+
+            let filepath = match options.filename_encoding.decode(&filepath_bytes[..filepath_end]) {
+                Some(filepath) => filepath,
+                None => {
+                    return Err(GobError::InvalidUtf8Filepath(filepath_bytes));
+                }
+            };
+
+            // Checked before the separators are converted to those of the platform.
+            let mixed = filepath.contains('/');
+
+            let filepath = path::from_gob_str(&filepath);
+
+            if mixed {
+                mixed_separators.push(filepath.clone());
+            }
+
+            if options.duplicates == DuplicatePolicy::Error && !filepaths.insert(filepath.clone()) {
+                return Err(GobError::DuplicateFilepath(filepath));
+            }
+
+            file_definitions.push(FileDefinition {
+                offset,
+                size,
+                filepath,
+            });
+        }
+
+        let header_end: u64 = match format {
+            GobFormat::DarkForces => 8,
+            GobFormat::JediKnight => 12,
+        };
+
+        let definitions_end = body_offset + 4 + definitions_len;
+
+        let relative = match options.offsets {
+            OffsetMode::Absolute => false,
+            OffsetMode::Relative => true,
+            // Files without data point nowhere, and some writers place them at
+            // offset 0, so they tell nothing about the mode.
+            OffsetMode::Detect => file_definitions.iter().filter(|file_definition| file_definition.size > 0).any(|file_definition| {
+                file_definition.offset < header_end
+                    || (file_definition.offset < definitions_end && file_definition.offset + file_definition.size > body_offset)
+            }),
+        };
+
+        for file_definition in &mut file_definitions {
+            if relative {
+                file_definition.offset += definitions_end;
+            }
+
+            if file_definition.offset + file_definition.size > length {
+                return Err(GobError::FileOutOfBounds(file_definition.filepath.clone()));
+            }
+        }
+
+        Ok(ParsedTable {
+            format,
+            version,
+            body_offset,
+            length,
+            file_definitions,
+            mixed_separators,
+        })
+    }
+
+    /// Checks whether the current archive object can be written as a GOB file,
+    /// collecting all problems instead of stopping at the first one.
+    /// 
+    /// The following is checked, according to the settings of the archive:
+    /// 
+    /// - Filepaths are representable in the filename encoding.
+    /// - Filepaths are relative and contain no `..` components, which would let
+    ///   them escape the directory the archive is extracted into.
+    /// - Filepaths contain no null bytes, which would terminate them early.
+    /// - Filepaths fit into the filepath field, including the null terminator.
+    /// - Filepaths are unique, regardless of separators and, if the archive is
+    ///   case-insensitive, of case.
+    /// - Offsets and sizes fit into the 32-bit fields.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// assert!(gob.validate().is_ok());
+    /// 
+    /// gob.set_case_insensitive(true);
+    /// 
+    /// gob.files.insert(PathBuf::from("FOO.BAR"), b"FOOBAR".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("a".repeat(200)), b"a".to_vec());
+    /// 
+    /// let errors = gob.validate().unwrap_err();
+    /// 
+    /// assert_eq!(errors.len(), 2);
+    /// 
+    /// assert!(matches!(&errors[0], GobError::DuplicateFilepath(filepath) if filepath == &PathBuf::from("FOO.BAR")));
+    /// 
+    /// assert!(matches!(&errors[1], GobError::FilepathTooLong { .. }));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<GobError>> {
+        let mut errors: Vec<GobError> = Vec::new();
+
+        let entries = self.entries();
+
+        let filepath_len = self.format.filepath_len();
+
+        let alignment = self.data_alignment.max(1) as u64;
+
+        let definitions_len = (8 + filepath_len as u64) * entries.len() as u64;
+
+        let mut file_data_offset: u64 = match self.format {
+            GobFormat::DarkForces => 8,
+            GobFormat::JediKnight => self.body_offset.max(12) as u64 + 4 + definitions_len,
+        };
+
+        let mut filepaths = HashSet::new();
+
+        for (i, &(filepath, file_data)) in entries.iter().enumerate() {
+            if i == u32::MAX as usize {
+                errors.push(GobError::SizeOverflow {
+                    path: filepath.clone(),
+                    size: entries.len() as u64,
+                });
+            }
+
+            let key = if self.case_insensitive || self.filename_case != FilenameCase::Preserve {
+                path::fold_case(&path::normalize(filepath))
+            } else {
+                path::normalize(filepath)
+            };
+
+            if !filepaths.insert(key) {
+                errors.push(GobError::DuplicateFilepath(filepath.clone()));
+            }
+
+            if !path::is_contained(filepath) {
+                errors.push(GobError::UnsafePath(filepath.clone()));
+            }
+
+            match path::to_gob_bytes(filepath, self.filename_encoding) {
+                Some(filepath_bytes) if filepath_bytes.contains(&0) => {
+                    errors.push(GobError::NullInPath(filepath.clone()));
+                }
+                Some(filepath_bytes) if filepath_bytes.len() >= filepath_len => {
+                    errors.push(GobError::FilepathTooLong {
+                        path: filepath.clone(),
+                        len: filepath_bytes.len(),
+                        max: filepath_len - 1,
+                    });
+                }
+                Some(_) => {}
+                None => errors.push(GobError::UnrepresentableFilepath(filepath.clone())),
+            }
+
+            file_data_offset = file_data_offset.next_multiple_of(alignment);
+
+            if file_data_offset > u32::MAX as u64 {
+                errors.push(GobError::SizeOverflow {
+                    path: filepath.clone(),
+                    size: file_data_offset,
+                });
+            }
+
+            if file_data.len() as u64 > u32::MAX as u64 {
+                errors.push(GobError::SizeOverflow {
+                    path: filepath.clone(),
+                    size: file_data.len() as u64,
+                });
+            }
+
+            file_data_offset += file_data.len() as u64;
+        }
+
+        if let (GobFormat::DarkForces, Some(&(filepath, _))) = (self.format, entries.last()) {
+            if file_data_offset > u32::MAX as u64 {
+                errors.push(GobError::SizeOverflow {
+                    path: filepath.clone(),
+                    size: file_data_offset,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Generates the data (bytes) for a GOB file representing the current archive object.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("fizz.buzz"),
+    ///     b"fizzbuzz".to_vec(),
+    /// );
+    /// 
+    /// let data = gob.as_bytes().unwrap();
+    /// 
+    /// assert_eq!(&data[..4], Vec::from(b"GOB "));
+    /// 
+    /// assert_eq!(&data[4..8], Vec::from(0x14u32.to_le_bytes()));
+    /// 
+    /// assert_eq!(&data[8..12], Vec::from(12u32.to_le_bytes()));
+    /// 
+    /// assert_eq!(&data[12..16], Vec::from(2u32.to_le_bytes()));
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.baz"),
+    ///     b"foobaz".to_vec(),
+    /// );
+    /// 
+    /// let data = gob.as_bytes().unwrap();
+    /// 
+    /// assert_eq!(&data[12..16], Vec::from(3u32.to_le_bytes()));
+    /// ```
+    /// 
+    /// An archive without files is valid, consisting of the header directly
+    /// followed by the empty table of file definitions. Use
+    /// [`DirectoryOptions::require_non_empty`] to reject empty directories when
+    /// packing instead:
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let data = Gob::new().as_bytes()?;
+    /// 
+    ///     assert_eq!(data, b"GOB \x14\x00\x00\x00\x0c\x00\x00\x00\x00\x00\x00\x00");
+    /// 
+    ///     assert!(Gob::from_bytes(&data)?.is_empty());
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_as_bytes_empty");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(&path)?;
+    /// 
+    ///     let gob = Gob::from_directory(&path)?;
+    /// 
+    ///     assert!(gob.is_empty());
+    /// 
+    ///     assert_eq!(gob.as_bytes()?, data);
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Fails if the offset or size of a file does not fit into 32 bits:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.set_data_alignment(u32::MAX);
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// assert!(matches!(
+    ///     gob.as_bytes(),
+    ///     Err(GobError::SizeOverflow { path, .. }) if path == PathBuf::from("fizz.buzz"),
+    /// ));
+    /// ```
+    /// 
+    /// Fails if a filepath leaves no room for the null terminator in the
+    /// filepath field:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("a".repeat(127)), b"foobar".to_vec());
+    /// 
+    /// let data = gob.as_bytes().unwrap();
+    /// 
+    /// assert_eq!(data[24 + 127], 0);
+    /// 
+    /// for len in [128, 129] {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("a".repeat(len)), b"foobar".to_vec());
+    /// 
+    ///     assert!(matches!(
+    ///         gob.as_bytes(),
+    ///         Err(GobError::FilepathTooLong { len: actual, max: 127, .. }) if actual == len,
+    ///     ));
+    /// }
+    /// ```
+    /// 
+    /// Fails if a filepath contains a null byte, which would terminate it early
+    /// in the filepath field:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar\0baz"), b"foobar".to_vec());
+    /// 
+    /// assert!(matches!(gob.as_bytes(), Err(GobError::NullInPath(_))));
+    /// ```
+    /// 
+    /// Fails if a filepath is absolute or contains `..` components, which would
+    /// let it escape the directory the archive is extracted into:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// for filepath in ["/etc/passwd", "../foo.bar", "C:\\evil.dll"] {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from(filepath), b"foobar".to_vec());
+    /// 
+    ///     assert!(matches!(gob.as_bytes(), Err(GobError::UnsafePath(_))));
+    /// }
+    /// ```
+    /// 
+    /// Fails if two filepaths only differ in their separators, like
+    /// [`Gob::validate`] reports:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("a/b"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("a\\b"), b"fizzbuzz".to_vec());
+    /// 
+    /// assert!(matches!(gob.as_bytes(), Err(GobError::DuplicateFilepath(_))));
+    /// 
+    /// assert!(gob.validate().is_err());
+    /// ```
+    /// 
+    /// Writes all integers in little-endian byte order, regardless of the byte
+    /// order of the host:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_body_offset(0x0110);
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), vec![0; 0x0102]);
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     // Body offset.
+    ///     assert_eq!(data[8..12], [0x10, 0x01, 0x00, 0x00]);
+    /// 
+    ///     // File count.
+    ///     assert_eq!(data[0x110..0x114], [0x01, 0x00, 0x00, 0x00]);
+    /// 
+    ///     // Offset of the file, following the file definitions.
+    ///     assert_eq!(data[0x114..0x118], (0x114u32 + 136).to_le_bytes());
+    /// 
+    ///     assert_eq!(data[0x114..0x118], [0x9c, 0x01, 0x00, 0x00]);
+    /// 
+    ///     // Size of the file.
+    ///     assert_eq!(data[0x118..0x11c], [0x02, 0x01, 0x00, 0x00]);
+    /// 
+    ///     let parsed = Gob::from_bytes(&data)?;
+    /// 
+    ///     assert_eq!(parsed.get("foo.bar").map(|data| data.len()), Some(0x0102));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn as_bytes(&self) -> Result<Vec<u8>, GobError> {
+        let mut bytes: Vec<u8> = Vec::new();
+
+        self.write_to(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Writes the data (bytes) for a GOB file representing the current archive
+    /// object to a given writer, e.g. a [`fs::File`] or a [`std::net::TcpStream`].
+    /// 
+    /// As opposed to [`Gob::as_bytes`], the data of the files is streamed to the
+    /// writer directly instead of being copied into one buffer first.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data: Vec<u8> = Vec::new();
+    /// 
+    ///     gob.write_to(&mut data)?;
+    /// 
+    ///     assert_eq!(data.len(), 16 + 136 + 6);
+    /// 
+    ///     assert_eq!(&data[data.len() - 6..], b"foobar");
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), GobError> {
+        #[cfg(feature = "compression")]
+        if self.compression != CompressionCodec::None {
+            return self.compressed()?.write_to(writer);
+        }
+
+        if let Some(image) = self.raw_image() {
+            writer.write_all(&image)?;
+
+            writer.flush()?;
+
+            return Ok(());
+        }
+
+        let entries = self.entries();
+
+        let table = self.encode_table(&entries)?;
+
+        Self::write_with_table(&table, &entries, writer, |_| {})?;
+
+        Ok(())
+    }
+
+    /// Writes the files of the current archive object into a writer as a ZIP
+    /// archive, in the order they would be written into a GOB archive.
+    /// 
+    /// The filepaths are separated by slashes, as ZIP archives require. The
+    /// files are deflated and carry fixed timestamps, so the output only depends
+    /// on the files. Fails with [`GobError::UnrepresentableFilepath`] if a
+    /// filepath is not valid UTF-8. See [`Gob::from_zip`] for an example.
+    /// 
+    /// Requires the `zip` feature.
+    #[cfg(feature = "zip")]
+    pub fn to_zip<W: Write + Seek>(&self, writer: &mut W) -> Result<(), GobError> {
+        let mut archive = zip::ZipWriter::new(writer);
+
+        for (filepath, file_data) in self.entries() {
+            archive
+                .start_file(Self::slash_separated(filepath)?, zip::write::SimpleFileOptions::default())
+                .map_err(io::Error::from)?;
+
+            archive.write_all(file_data)?;
+        }
+
+        archive.finish().map_err(io::Error::from)?;
+
+        Ok(())
+    }
+
+    /// Writes the files of the current archive object into a writer as a tar
+    /// archive, in the order they would be written into a GOB archive.
+    /// 
+    /// The filepaths are separated by slashes. The files are written as regular
+    /// files with mode `644` and fixed timestamps, so the output only depends on
+    /// the files. Fails with [`GobError::UnrepresentableFilepath`] if a filepath
+    /// is not valid UTF-8. See [`Gob::from_tar`] for an example.
+    /// 
+    /// Requires the `tar` feature.
+    #[cfg(feature = "tar")]
+    pub fn to_tar<W: Write>(&self, writer: &mut W) -> Result<(), GobError> {
+        let mut archive = tar::Builder::new(writer);
+
+        for (filepath, file_data) in self.entries() {
+            let mut header = tar::Header::new_gnu();
+
+            header.set_size(file_data.len() as u64);
+
+            header.set_mode(0o644);
+
+            archive.append_data(&mut header, Self::slash_separated(filepath)?, file_data.as_slice())?;
+        }
+
+        archive.into_inner()?.flush()?;
+
+        Ok(())
+    }
+
+    /// Joins the components of a filepath with slashes, as other kinds of
+    /// archives separate filepaths regardless of the platform.
+    #[cfg(any(feature = "zip", feature = "tar"))]
+    fn slash_separated(filepath: &Path) -> Result<String, GobError> {
+        let components: Option<Vec<&str>> = filepath.iter().map(|component| component.to_str()).collect();
+
+        match components {
+            Some(components) => Ok(components.join("/")),
+            None => Err(GobError::UnrepresentableFilepath(filepath.to_path_buf())),
+        }
+    }
+
+    /// Writes the header, the data of the files and the file definitions of the
+    /// archive, reporting the progress after the data of each file.
+    fn write_with_table<W: Write, F: FnMut(Progress)>(
+        table: &Table,
+        entries: &[Entry],
+        writer: &mut W,
+        mut progress: F,
+    ) -> std::io::Result<()> {
+        writer.write_all(&table.head)?;
+
+        let mut current = Progress {
+            files_done: 0,
+            files_total: entries.len(),
+            bytes_done: 0,
+            bytes_total: entries.iter().map(|(_, file_data)| file_data.len() as u64).sum(),
+        };
+
+        for ((_, file_data), &padding) in entries.iter().zip(&table.padding) {
+            if let Some(padding) = padding {
+                writer.write_all(&vec![0; padding as usize])?;
+
+                writer.write_all(file_data)?;
+            }
+
+            current.files_done += 1;
+
+            current.bytes_done += file_data.len() as u64;
+
+            progress(current);
+        }
+
+        writer.write_all(&table.tail)?;
+
+        writer.flush()
+    }
+
+    /// Creates an uncompressed copy of the current archive object, whose files
+    /// hold the compressed data of the files of the current archive object.
+    #[cfg(feature = "compression")]
+    fn compressed(&self) -> Result<Gob, GobError> {
+        let mut files = GobMap::with_capacity(self.files.len());
+
+        for (filepath, data) in &self.files {
+            files.insert(filepath.clone(), self.compression.compress(data)?);
+        }
+
+        Ok(Gob {
+            files,
+            metadata: HashMap::new(),
+            raw_layout: None,
+            compression: CompressionCodec::None,
+            ..*self
+        })
+    }
+
+    /// Gets the files of the archive in the order they are written in.
+    fn entries(&self) -> Vec<Entry<'_>> {
+        let mut entries: Vec<Entry<'_>> = self.files.iter().collect();
+
+        if self.deterministic {
+            entries.sort_by_cached_key(|(filepath, _)| path::sort_key(filepath));
+        }
+
+        entries
+    }
+
+    /// Generates the header and the file definitions of the archive.
+    /// 
+    /// The offsets of the files are computed up front, so the data of the files
+    /// must be written in the same order as the given entries.
+    /// 
+    /// Like when parsing, all integers are converted explicitly from and to
+    /// little-endian bytes, as the format is little-endian regardless of the
+    /// host, so native-endian conversions must never be used.
+    fn encode_table(&self, entries: &[Entry]) -> Result<Table, GobError> {
+        let mut filepaths = HashSet::new();
+
+        for filepath in self.files.keys() {
+            let key = if self.case_insensitive || self.filename_case != FilenameCase::Preserve {
+                path::fold_case(&path::normalize(filepath))
+            } else {
+                path::normalize(filepath)
+            };
+
+            if !filepaths.insert(key) {
+                return Err(GobError::DuplicateFilepath(filepath.clone()));
+            }
+        }
+
+        let filepath_len = self.format.filepath_len();
+
+        let file_count = match u32::try_from(entries.len()) {
+            Ok(file_count) => file_count,
+            Err(_) => {
+                return Err(GobError::SizeOverflow {
+                    path: entries[u32::MAX as usize].0.clone(),
+                    size: entries.len() as u64,
+                });
+            }
+        };
+
+        let mut file_data_offset: u64 = match self.format {
+            GobFormat::DarkForces => 8,
+            GobFormat::JediKnight => self.body_offset.max(12) as u64 + 4 + (8 + filepath_len as u64) * file_count as u64,
+        };
+
+        let mut definitions: Vec<u8> = Vec::new();
+
+        let mut padding: Vec<Option<u32>> = Vec::new();
+
+        let mut offsets: Vec<u32> = Vec::new();
+
+        // The offsets of data already written, if deduplicating.
+        let mut shared_offsets: HashMap<&[u8], u32> = HashMap::new();
+
+        definitions.extend(&file_count.to_le_bytes());
+
+        for &(filepath, file_data) in entries {
+            let size = match u32::try_from(file_data.len()) {
+                Ok(size) => size,
+                Err(_) => {
+                    return Err(GobError::SizeOverflow {
+                        path: filepath.clone(),
+                        size: file_data.len() as u64,
+                    });
+                }
+            };
+
+            let offset = match shared_offsets.get(file_data.as_slice()) {
+                Some(&offset) => {
+                    padding.push(None);
+
+                    offset
+                }
+                None => {
+                    let aligned_offset = file_data_offset.next_multiple_of(self.data_alignment.max(1) as u64);
+
+                    padding.push(Some((aligned_offset - file_data_offset) as u32));
+
+                    file_data_offset = aligned_offset;
+
+                    let offset = match u32::try_from(file_data_offset) {
+                        Ok(offset) => offset,
+                        Err(_) => {
+                            return Err(GobError::SizeOverflow {
+                                path: filepath.clone(),
+                                size: file_data_offset,
+                            });
+                        }
+                    };
+
+                    file_data_offset += size as u64;
+
+                    if self.deduplicating {
+                        shared_offsets.insert(file_data, offset);
+                    }
+
+                    offset
+                }
+            };
+
+            offsets.push(offset);
+
+            definitions.extend(&offset.to_le_bytes());
+
+            definitions.extend(&size.to_le_bytes());
+
+            let filepath_bytes = self.filename_case.apply(Self::encode_filepath(filepath, self.filename_encoding, self.format)?);
+
+            definitions.extend(&filepath_bytes);
+
+            definitions.extend(vec![0; filepath_len - filepath_bytes.len()]);
+        }
+
+        let size = match self.format {
+            GobFormat::DarkForces => file_data_offset + definitions.len() as u64,
+            GobFormat::JediKnight => file_data_offset,
+        };
+
+        let trailing_padding = match self.padded_size {
+            Some(padded_size) if size > padded_size => {
+                return Err(GobError::ExceedsPaddedSize {
+                    size,
+                    padded_size,
+                });
+            }
+            Some(padded_size) => padded_size - size,
+            None => 0,
+        };
+
+        let mut header: Vec<u8> = Vec::new();
+
+        header.extend(self.format.signature());
+
+        match self.format {
+            GobFormat::DarkForces => {
+                let body_offset = match (u32::try_from(file_data_offset), entries.last()) {
+                    (Ok(body_offset), _) => body_offset,
+                    (Err(_), Some(&(filepath, _))) => {
+                        return Err(GobError::SizeOverflow {
+                            path: filepath.clone(),
+                            size: file_data_offset,
+                        });
+                    }
+                    (Err(_), None) => unreachable!("Body offset of empty archive should fit into header"),
+                };
+
+                header.extend(&body_offset.to_le_bytes());
+
+                definitions.extend(vec![0; trailing_padding as usize]);
+
+                Ok(Table {
+                    head: header,
+                    padding,
+                    offsets,
+                    tail: definitions,
+                })
+            }
+            GobFormat::JediKnight => {
+                header.extend(&self.version.to_le_bytes());
+
+                let body_offset = self.body_offset.max(12);
+
+                header.extend(&body_offset.to_le_bytes());
+
+                header.extend(vec![0; body_offset as usize - 12]);
+
+                header.extend(definitions);
+
+                Ok(Table {
+                    head: header,
+                    padding,
+                    offsets,
+                    tail: vec![0; trailing_padding as usize],
+                })
+            }
+        }
+    }
+
+    /// Writes the current archive object to a GOB file at a given [`Path`].
+    /// 
+    /// The data is first written to a temporary file next to the destination,
+    /// which then replaces the destination. Thus, an existing file is never left
+    /// truncated if writing fails midway.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_file.GOB");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let gob = Gob::from_file(&path)?;
+    /// 
+    ///     assert_eq!(gob.files.get(&PathBuf::from("foo.bar")), Some(&b"foobar".to_vec()));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Replaces an existing file, but leaves it untouched if the archive cannot
+    /// be written, e.g. because a filepath is too long:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_to_file_replace.GOB");
+    /// 
+    ///     std::fs::write(&path, b"outdated")?;
+    /// 
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     assert_eq!(std::fs::read(&path)?, gob.as_bytes()?);
+    /// 
+    ///     gob.files.insert(PathBuf::from("a".repeat(128)), b"a".to_vec());
+    /// 
+    ///     assert!(matches!(gob.to_file(&path), Err(GobError::FilepathTooLong { .. })));
+    /// 
+    ///     assert_eq!(Gob::from_file(&path)?.len(), 1);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_file(&self, path: &Path) -> Result<(), GobError> {
+        self.to_file_with_progress(path, |_| {})
+    }
+
+    /// Writes the current archive object to a GOB file at a given [`Path`] like
+    /// [`Gob::to_file`], calling a given closure with the [`Progress`] after the
+    /// data of each file has been written.
+    /// 
+    /// If the archive is reproduced according to a captured raw layout (see
+    /// [`ParseOptions::preserve_layout`]), it is written at once, so the
+    /// closure is only called once, after all of it has been written.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_file_with_progress.GOB");
+    /// 
+    ///     let mut reports = Vec::new();
+    /// 
+    ///     gob.to_file_with_progress(&path, |progress| {
+    ///         reports.push((progress.files_done, progress.bytes_done, progress.bytes_total));
+    ///     })?;
+    /// 
+    ///     assert_eq!(reports, [(1, 6, 14), (2, 14, 14)]);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Reports progress once when reproducing a captured raw layout:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     data.extend(b"TRAILER");
+    /// 
+    ///     let options = ParseOptions::new().preserve_layout(true);
+    /// 
+    ///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_to_file_with_progress_raw.GOB");
+    /// 
+    ///     let mut reports = Vec::new();
+    /// 
+    ///     parsed.to_file_with_progress(&path, |progress| {
+    ///         reports.push((progress.files_done, progress.bytes_done, progress.bytes_total));
+    ///     })?;
+    /// 
+    ///     assert_eq!(reports, [(2, 14, 14)]);
+    /// 
+    ///     assert_eq!(std::fs::read(&path)?, data);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_file_with_progress<F: FnMut(Progress)>(&self, path: &Path, mut progress: F) -> Result<(), GobError> {
+        #[cfg(feature = "compression")]
+        if self.compression != CompressionCodec::None {
+            return self.compressed()?.to_file_with_progress(path, progress);
+        }
+
+        let entries = self.entries();
+
+        let image = self.raw_image();
+
+        let table = match image {
+            Some(_) => None,
+            None => Some(self.encode_table(&entries)?),
+        };
+
+        let filename = match path.file_name() {
+            Some(filename) => filename,
+            None => {
+                return Err(Error::new(ErrorKind::InvalidInput, "Path does not name a file.").into());
+            }
+        };
+
+        let mut temp_filename = OsString::from(".");
+
+        temp_filename.push(filename);
+
+        temp_filename.push(".tmp");
+
+        let temp_path = path.with_file_name(temp_filename);
+
+        let result = fs::File::create(&temp_path).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+
+            match (&image, &table) {
+                (Some(image), _) => {
+                    writer.write_all(image)?;
+
+                    writer.flush()?;
+
+                    let bytes_total = entries.iter().map(|(_, file_data)| file_data.len() as u64).sum();
+
+                    progress(Progress {
+                        files_done: entries.len(),
+                        files_total: entries.len(),
+                        bytes_done: bytes_total,
+                        bytes_total,
+                    });
+                }
+                (None, Some(table)) => Self::write_with_table(table, &entries, &mut writer, progress)?,
+                (None, None) => unreachable!("Table should be encoded without raw layout"),
+            }
+
+            writer.get_ref().sync_all()
+        });
+
+        let result = result.and_then(|_| fs::rename(&temp_path, path));
+
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+
+        Ok(result?)
+    }
+
+    /// Adds or replaces a single file within an existing GOB file at a given
+    /// [`Path`], without rewriting the data of the other files.
+    /// 
+    /// The data is appended to the end of the archive, followed by the updated
+    /// file definitions, and the header is patched to point to them. This is
+    /// cheap, but leaves unreferenced space behind: the previous file definitions
+    /// of [`GobFormat::JediKnight`] archives and, when a file is replaced, its
+    /// previous data. Use [`Gob::compact_file`] to reclaim it.
+    /// 
+    /// Filepaths are encoded as UTF-8. Unlike [`Gob::to_file`], the file is
+    /// modified in place, so it is left corrupt if writing fails midway.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_append_to_file.GOB");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     Gob::append_to_file(&path, "fizz.buzz", b"fizzbuzz")?;
+    /// 
+    ///     Gob::append_to_file(&path, "foo.bar", b"foobaz")?;
+    /// 
+    ///     let gob = Gob::from_file(&path)?;
+    /// 
+    ///     assert_eq!(gob.get("foo.bar"), Some(&b"foobaz"[..]));
+    /// 
+    ///     assert_eq!(gob.get("fizz.buzz"), Some(&b"fizzbuzz"[..]));
+    /// 
+    ///     let size = std::fs::metadata(&path)?.len();
+    /// 
+    ///     Gob::compact_file(&path)?;
+    /// 
+    ///     assert!(std::fs::metadata(&path)?.len() < size);
+    /// 
+    ///     assert!(Gob::from_file(&path)? == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Filepaths are checked before anything is written:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_append_to_file_checked.GOB");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let size = std::fs::metadata(&path)?.len();
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::append_to_file(&path, "foo\0.bar", b"fizzbuzz"),
+    ///         Err(GobError::NullInPath(_))
+    ///     ));
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::append_to_file(&path, "..\\evil.dll", b"fizzbuzz"),
+    ///         Err(GobError::UnsafePath(_))
+    ///     ));
+    /// 
+    ///     assert_eq!(std::fs::metadata(&path)?.len(), size);
+    /// 
+    ///     assert!(Gob::from_file(&path)? == gob);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn append_to_file(path: &Path, filepath: impl Into<PathBuf>, data: &[u8]) -> Result<(), GobError> {
+        let filepath = filepath.into();
+
+        let mut file = fs::OpenOptions::new().read(true).write(true).open(path)?;
+
+        let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+
+        let ParsedTable {
+            format,
+            mut file_definitions,
+            ..
+        } = Self::read_file_definitions(&mut file, &options)?;
+
+        Self::encode_filepath(&filepath, FilenameEncoding::Utf8, format)?;
+
+        let filepath = path::normalize(&filepath);
+
+        let filepath_len = format.filepath_len();
+
+        let header_len: u64 = match format {
+            GobFormat::DarkForces => 4,
+            GobFormat::JediKnight => 8,
+        };
+
+        file.seek(SeekFrom::Start(header_len))?;
+
+        let table_offset = u32::from_le_bytes(byte::slice!(&mut file, 4, "body offset")) as u64;
+
+        // The file definitions of the Dark Forces variant follow the data of
+        // the files, so they are overwritten by the appended data.
+        let data_offset = match format {
+            GobFormat::DarkForces => table_offset,
+            GobFormat::JediKnight => file.seek(SeekFrom::End(0))?,
+        };
+
+        let new_table_offset = data_offset + data.len() as u64;
+
+        if u32::try_from(new_table_offset).is_err() || u32::try_from(file_definitions.len() + 1).is_err() {
+            return Err(GobError::SizeOverflow {
+                path: filepath,
+                size: new_table_offset,
+            });
+        }
+
+        let file_definition = FileDefinition {
+            offset: data_offset,
+            size: data.len() as u64,
+            filepath,
+        };
+
+        match file_definitions
+            .iter_mut()
+            .rev()
+            .find(|existing| existing.filepath == file_definition.filepath)
+        {
+            Some(existing) => *existing = file_definition,
+            None => file_definitions.push(file_definition),
+        }
+
+        let mut table: Vec<u8> = Vec::new();
+
+        table.extend(&(file_definitions.len() as u32).to_le_bytes());
+
+        for file_definition in &file_definitions {
+            table.extend(&(file_definition.offset as u32).to_le_bytes());
+
+            table.extend(&(file_definition.size as u32).to_le_bytes());
+
+            // Filepaths were either decoded from UTF-8 or checked above.
+            let filepath_bytes = path::to_gob_bytes(&file_definition.filepath, FilenameEncoding::Utf8)
+                .expect("Filepath should be representable as UTF-8");
+
+            table.extend(&filepath_bytes);
+
+            table.extend(vec![0; filepath_len.saturating_sub(filepath_bytes.len())]);
+        }
+
+        file.seek(SeekFrom::Start(data_offset))?;
+
+        file.write_all(data)?;
+
+        file.write_all(&table)?;
+
+        file.set_len(new_table_offset + table.len() as u64)?;
+
+        file.seek(SeekFrom::Start(header_len))?;
+
+        file.write_all(&(new_table_offset as u32).to_le_bytes())?;
+
+        Ok(file.sync_all()?)
+    }
+
+    /// Rewrites a GOB file at a given [`Path`] without any unreferenced space,
+    /// e.g. as left behind by [`Gob::append_to_file`].
+    /// 
+    /// Equivalent to [`Gob::rewrite`] with the same source and destination.
+    pub fn compact_file(path: &Path) -> Result<(), GobError> {
+        Self::rewrite(path, path)
+    }
+
+    /// Reads a GOB file at a given source [`Path`] and writes only the data it
+    /// references, without any gaps, to a GOB file at a given destination
+    /// [`Path`], producing a minimal archive.
+    /// 
+    /// The file is written like by [`Gob::to_file`], keeping the variant of the
+    /// format and the order of the files, but resolving files sharing the same
+    /// filepath to the one defined last.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.set_data_alignment(512);
+    /// 
+    ///     let src = std::env::temp_dir().join("gob_rs_rewrite_src.GOB");
+    /// 
+    ///     let dst = std::env::temp_dir().join("gob_rs_rewrite_dst.GOB");
+    /// 
+    ///     gob.to_file(&src)?;
+    /// 
+    ///     Gob::append_to_file(&src, "foo.bar", b"foobaz")?;
+    /// 
+    ///     Gob::rewrite(&src, &dst)?;
+    /// 
+    ///     let gob = Gob::from_file(&dst)?;
+    /// 
+    ///     assert_eq!(std::fs::metadata(&dst)?.len(), gob.packed_size());
+    /// 
+    ///     assert_eq!(gob.packed_size() - gob.total_size(), 16 + 136);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rewrite(src: &Path, dst: &Path) -> Result<(), GobError> {
+        let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+
+        Self::from_file_with_options(src, options)?.to_file(dst)
+    }
+
+    /// Creates a new [`Gob`] object.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// ```
+    /// 
+    /// Packs and parses an archive without any files:
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(data, b"GOB \x14\x00\x00\x00\x0c\x00\x00\x00\x00\x00\x00\x00");
+    /// 
+    ///     assert!(Gob::from_bytes(&data)?.is_empty());
+    /// 
+    ///     gob.set_format(GobFormat::DarkForces);
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(data, b"GOB\x0a\x08\x00\x00\x00\x00\x00\x00\x00");
+    /// 
+    ///     assert!(Gob::from_bytes(&data)?.is_empty());
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_empty_directory");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(&path)?;
+    /// 
+    ///     assert!(Gob::from_directory(&path)?.is_empty());
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        let files = GobMap::new();
+
+        Self::from(files)
+    }
+
+    /// Creates a new empty [`Gob`] object with the default settings, whose
+    /// [`Gob::files`] can hold at least a given amount of files without
+    /// reallocating.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::with_capacity(100);
+    /// 
+    /// assert!(gob.is_empty());
+    /// 
+    /// assert!(gob.files.capacity() >= 100);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(GobMap::with_capacity(capacity))
+    }
+
+    /// Gets the variant of the GOB format the archive is written in.
+    /// 
+    /// Archives parsed from GOB files keep the variant they were parsed from,
+    /// otherwise [`GobFormat::JediKnight`] is used.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.format(), GobFormat::JediKnight);
+    /// ```
+    pub fn format(&self) -> GobFormat {
+        self.format
+    }
+
+    /// Sets the variant of the GOB format the archive is written in.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.set_format(GobFormat::DarkForces);
+    /// 
+    /// assert_eq!(gob.format(), GobFormat::DarkForces);
+    /// ```
+    pub fn set_format(&mut self, format: GobFormat) {
+        self.format = format;
+    }
+
+    /// Gets the layout captured when parsing the archive, if it was captured
+    /// (see [`ParseOptions::preserve_layout`]).
+    /// 
+    /// While the files still fit the layout, i.e. the archive contains exactly
+    /// the files parsed with data of the same sizes and the variant of the format
+    /// is unchanged, the archive is written byte for byte like the parsed one,
+    /// including padding, unreferenced data and bytes following the null
+    /// terminators of filepaths. Settings affecting the layout, such as the
+    /// alignment of data, are then ignored. Otherwise, the archive is written as
+    /// if no layout was captured.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_data_alignment(4);
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Leave garbage past the null terminator of the first filepath.
+    ///     data[32..36].copy_from_slice(b"JUNK");
+    /// 
+    ///     // Fill the padding between the files with non-zero bytes.
+    ///     data[294..296].copy_from_slice(b"\xff\xff");
+    /// 
+    ///     data.extend(b"TRAILER");
+    /// 
+    ///     let options = ParseOptions::new().preserve_layout(true);
+    /// 
+    ///     let mut parsed = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+    /// 
+    ///     assert!(parsed.raw_layout().is_some());
+    /// 
+    ///     assert_eq!(parsed.as_bytes()?, data);
+    /// 
+    ///     // Data of the same size keeps the layout.
+    ///     parsed.files.insert(PathBuf::from("foo.bar"), b"FOOBAR".to_vec());
+    /// 
+    ///     assert_eq!(parsed.as_bytes()?.len(), data.len());
+    /// 
+    ///     // Data of another size does not.
+    ///     parsed.files.insert(PathBuf::from("foo.bar"), b"foo".to_vec());
+    /// 
+    ///     assert_eq!(parsed.as_bytes()?.len(), 16 + 2 * 136 + 3 + 8);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn raw_layout(&self) -> Option<&RawLayout> {
+        self.raw_layout.as_ref()
+    }
+
+    /// Discards the layout captured when parsing the archive, so that it is
+    /// written like any other archive object.
+    pub fn clear_raw_layout(&mut self) {
+        self.raw_layout = None;
+    }
+
+    /// Gets the captured raw layout of the archive if it still applies, i.e. if
+    /// the archive has the same variant of the format and contains exactly the
+    /// files of the layout at their original sizes.
+    fn fitting_raw_layout(&self) -> Option<&RawLayout> {
+        let layout = self.raw_layout.as_ref()?;
+
+        if self.padded_size.is_some() {
+            return None;
+        }
+
+        if layout.format != self.format || layout.definitions.len() != self.files.len() {
+            return None;
+        }
+
+        let fits = layout.definitions.iter().all(|file_definition| {
+            self.files
+                .get(&file_definition.filepath)
+                .is_some_and(|data| data.len() as u64 == file_definition.size)
+        });
+
+        if fits {
+            Some(layout)
+        } else {
+            None
+        }
+    }
+
+    /// Reproduces the archive according to the captured layout, if any and if
+    /// the files still fit it.
+    fn raw_image(&self) -> Option<Vec<u8>> {
+        let layout = self.fitting_raw_layout()?;
+
+        let mut image = vec![0; layout.length as usize];
+
+        for (offset, filler) in &layout.filler {
+            let offset = *offset as usize;
+
+            image[offset..offset + filler.len()].copy_from_slice(filler);
+        }
+
+        for file_definition in &layout.definitions {
+            let offset = file_definition.offset as usize;
+
+            let data = &self.files[&file_definition.filepath];
+
+            image[offset..offset + data.len()].copy_from_slice(data);
+        }
+
+        Some(image)
+    }
+
+    /// Gets the signature in the header of the archive, which is determined by
+    /// the variant of the format.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.signature(), b"GOB ");
+    /// 
+    /// gob.set_format(GobFormat::DarkForces);
+    /// 
+    /// assert_eq!(gob.signature(), b"GOB\x0a");
+    /// ```
+    pub fn signature(&self) -> &'static [u8; 4] {
+        self.format.signature()
+    }
+
+    /// Gets the version in the header of the archive, as parsed or to be written.
+    /// Defaults to `0x14`, the version written by the games.
+    /// 
+    /// Archives of the [`GobFormat::DarkForces`] variant do not store a version.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.version(), 0x14);
+    /// ```
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Sets the version written into the header of the archive, which is only
+    /// stored by the [`GobFormat::JediKnight`] variant.
+    /// 
+    /// Archives of other versions are only parsed when accepted through
+    /// [`ParseOptions::accepted_versions`].
+    pub fn set_version(&mut self, version: u32) {
+        self.version = version;
+    }
+
+    /// Gets the maximum length in bytes of filepaths within the archive, as
+    /// encoded in its filename encoding, which is the size of the filepath field
+    /// of the variant of the format minus the null terminator.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::GobFormat};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.max_filename_len(), 127);
+    /// 
+    /// gob.set_format(GobFormat::DarkForces);
+    /// 
+    /// assert_eq!(gob.max_filename_len(), 12);
+    /// ```
+    pub fn max_filename_len(&self) -> usize {
+        self.format.filepath_len() - 1
+    }
+
+    /// Gets the encoding the filepaths within the archive are written in.
+    /// 
+    /// Archives parsed from GOB files keep the encoding they were parsed with,
+    /// otherwise [`FilenameEncoding::Utf8`] is used.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::FilenameEncoding};
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.filename_encoding(), FilenameEncoding::Utf8);
+    /// ```
+    pub fn filename_encoding(&self) -> FilenameEncoding {
+        self.filename_encoding
+    }
+
+    /// Sets the encoding the filepaths within the archive are written in.
+    /// 
+    /// Generating a GOB file fails if a filepath contains characters which are
+    /// unrepresentable in the encoding.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError, format::FilenameEncoding};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.set_filename_encoding(FilenameEncoding::Latin1);
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("ÜBER.BM"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert!(gob.as_bytes().is_ok());
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("€.BM"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert!(matches!(gob.as_bytes(), Err(GobError::UnrepresentableFilepath(_))));
+    /// ```
+    pub fn set_filename_encoding(&mut self, filename_encoding: FilenameEncoding) {
+        self.filename_encoding = filename_encoding;
+    }
+
+    /// Gets the ASCII case the filepaths within the archive are written in.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, format::FilenameCase};
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.filename_case(), FilenameCase::Preserve);
+    /// ```
+    pub fn filename_case(&self) -> FilenameCase {
+        self.filename_case
+    }
+
+    /// Sets the ASCII case the filepaths within the archive are written in.
+    /// 
+    /// Only the written bytes are affected, not the filepaths within
+    /// [`Gob::files`]. Unless the case is preserved, generating a GOB file fails
+    /// if filepaths differ only in case, as they would be written identically.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError, format::FilenameCase};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_filename_case(FilenameCase::Uppercase);
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission/foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let parsed = Gob::from_bytes(&gob.as_bytes()?)?;
+    /// 
+    ///     let filepaths: Vec<&PathBuf> = parsed.paths().collect();
+    /// 
+    ///     assert_eq!(filepaths, [&PathBuf::from("MISSION/FOO.BAR")]);
+    /// 
+    ///     assert!(gob.contains("mission/foo.bar"));
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission/FOO.BAR"),
+    ///         b"FOOBAR".to_vec(),
+    ///     );
+    /// 
+    ///     assert!(matches!(gob.as_bytes(), Err(GobError::DuplicateFilepath(_))));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_filename_case(&mut self, filename_case: FilenameCase) {
+        self.filename_case = filename_case;
+    }
+
+    /// Gets the alignment, in bytes, of the offsets the data of files is written at.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.data_alignment(), 1);
+    /// ```
+    pub fn data_alignment(&self) -> u32 {
+        self.data_alignment
+    }
+
+    /// Sets the alignment, in bytes, of the offsets the data of files is written at.
+    /// 
+    /// The data of each file is preceded by as many zero bytes as needed to align
+    /// its offset. An alignment of `1`, the default, packs the data of files
+    /// without any gaps. An alignment of `0` is treated like `1`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_data_alignment(16);
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("a.b"), b"a".to_vec());
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let index = GobIndex::new(Cursor::new(&data))?;
+    /// 
+    ///     assert!(index.entries().iter().all(|file_definition| file_definition.offset % 16 == 0));
+    /// 
+    ///     let parsed = Gob::from_reader(&mut Cursor::new(&data))?;
+    /// 
+    ///     assert_eq!(parsed.files, gob.files);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_data_alignment(&mut self, data_alignment: u32) {
+        self.data_alignment = data_alignment;
+    }
+
+    /// Gets the offset of the file definitions written into the header of GOB
+    /// files of the [`GobFormat::JediKnight`] variant.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.body_offset(), 12);
+    /// ```
+    pub fn body_offset(&self) -> u32 {
+        self.body_offset
+    }
+
+    /// Sets the offset of the file definitions written into the header of GOB
+    /// files of the [`GobFormat::JediKnight`] variant, reserving the space
+    /// between the header and the file definitions, which is filled with zeros.
+    /// 
+    /// The default of `12` places the file definitions directly after the
+    /// header. Offsets below `12` are treated like `12`. The
+    /// [`GobFormat::DarkForces`] variant stores the file definitions after the
+    /// data of the files and is unaffected.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_body_offset(64);
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(&data[8..12], Vec::from(64u32.to_le_bytes()));
+    /// 
+    ///     assert!(data[12..64].iter().all(|&byte| byte == 0));
+    /// 
+    ///     assert!(Gob::from_bytes(&data)? == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_body_offset(&mut self, body_offset: u32) {
+        self.body_offset = body_offset;
+    }
+
+    /// Gets the size, in bytes, GOB files representing the archive are padded to
+    /// with zero bytes, if any.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert_eq!(gob.padded_size(), None);
+    /// ```
+    pub fn padded_size(&self) -> Option<u64> {
+        self.padded_size
+    }
+
+    /// Sets the size, in bytes, GOB files representing the archive are padded to
+    /// with zero bytes, e.g. to fill fixed-size slots on a medium.
+    /// 
+    /// The zero bytes are appended after the end of the archive, where parsing
+    /// ignores them. Writing fails with [`GobError::ExceedsPaddedSize`] if the
+    /// archive is larger than the padded size. A captured raw layout (see
+    /// [`ParseOptions::preserve_layout`]) is not reproduced while padding.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.set_padded_size(Some(2048));
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(data.len(), 2048);
+    /// 
+    ///     assert_eq!(gob.packed_size(), 2048);
+    /// 
+    ///     assert_eq!(Gob::from_bytes(&data)?, gob);
+    /// 
+    ///     gob.set_padded_size(Some(64));
+    /// 
+    ///     assert!(matches!(gob.as_bytes(), Err(GobError::ExceedsPaddedSize { size: 158, padded_size: 64 })));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_padded_size(&mut self, padded_size: Option<u64>) {
+        self.padded_size = padded_size;
+    }
+
+    /// Gets the codec the data of files is compressed with when writing.
+    /// Requires the `compression` feature.
+    #[cfg(feature = "compression")]
+    pub fn compression(&self) -> CompressionCodec {
+        self.compression
+    }
+
+    /// Sets the codec the data of files is compressed with when writing,
+    /// producing a nonstandard variant of the GOB format unless it is
+    /// [`CompressionCodec::None`], the default. See [`CompressionCodec`].
+    /// 
+    /// Sizes checked by [`Gob::validate`] refer to the uncompressed data, while
+    /// [`Gob::packed_size`] reports the size of the compressed archive. Requires
+    /// the `compression` feature.
+    #[cfg(feature = "compression")]
+    pub fn set_compression(&mut self, compression: CompressionCodec) {
+        self.compression = compression;
+    }
+
+    /// Checks whether filepaths within the archive are treated case-insensitively.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert!(!gob.is_case_insensitive());
+    /// ```
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Sets whether filepaths within the archive are treated case-insensitively,
+    /// like the games using GOB archives do.
+    /// 
+    /// If set, lookups ignore the ASCII case of filepaths, and generating a GOB
+    /// file fails if two filepaths only differ in their case.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.set_case_insensitive(true);
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("FOO.BAR"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert_eq!(gob.get("foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.bar"),
+    ///     b"fizzbuzz".to_vec(),
+    /// );
+    /// 
+    /// assert!(matches!(gob.as_bytes(), Err(GobError::DuplicateFilepath(_))));
+    /// ```
+    pub fn set_case_insensitive(&mut self, case_insensitive: bool) {
+        self.case_insensitive = case_insensitive;
+    }
+
+    /// Checks whether files with identical data share a single copy of it when
+    /// written.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert!(!gob.is_deduplicating());
+    /// ```
+    pub fn is_deduplicating(&self) -> bool {
+        self.deduplicating
+    }
+
+    /// Sets whether files with identical data share a single copy of it when
+    /// written, by pointing their file definitions at the same offset.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     for filepath in ["a.mat", "b.mat", "c.mat"] {
+    ///         gob.files.insert(PathBuf::from(filepath), b"placeholder".to_vec());
+    ///     }
+    /// 
+    ///     gob.set_deduplicating(true);
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert_eq!(data.len(), 16 + 3 * 136 + 11);
+    /// 
+    ///     let index = GobIndex::new(std::io::Cursor::new(&data))?;
+    /// 
+    ///     assert!(index.entries().iter().all(|file_definition| file_definition.offset == 16 + 3 * 136));
+    /// 
+    ///     assert!(Gob::from_bytes(&data)? == gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_deduplicating(&mut self, deduplicating: bool) {
+        self.deduplicating = deduplicating;
+    }
+
+    /// Checks whether files are written sorted by their filepaths, rather than in
+    /// the order of [`Gob::files`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let gob = Gob::new();
+    /// 
+    /// assert!(!gob.is_deterministic());
+    /// ```
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Sets whether files are written sorted by their filepaths, rather than in
+    /// the order of [`Gob::files`]. This way, two archive objects containing the
+    /// same files always generate the same data.
+    /// 
+    /// Filepaths are sorted by their components, compared byte by byte after
+    /// lowercasing ASCII letters, regardless of whether they are separated by
+    /// slashes or backslashes. Filepaths equal in this regard are sorted by their
+    /// components as they are.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut a = Gob::new();
+    /// 
+    ///     a.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     a.files.insert(PathBuf::from("Fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut b = Gob::new();
+    /// 
+    ///     b.files.insert(PathBuf::from("Fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     b.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     assert_ne!(a.as_bytes()?, b.as_bytes()?);
+    /// 
+    ///     a.set_deterministic(true);
+    /// 
+    ///     b.set_deterministic(true);
+    /// 
+    ///     assert_eq!(a.as_bytes()?, b.as_bytes()?);
+    /// 
+    ///     assert_eq!(&a.as_bytes()?[24..33], b"Fizz.buzz");
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Gets the amount of files in the archive.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert_eq!(gob.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Checks whether the archive contains no files.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::core::Gob;
     /// 
     /// let gob = Gob::new();
+    /// 
+    /// assert!(gob.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Iterates over the filepaths and the data of the files, in insertion
+    /// order. See [`Gob::entries_ordered`] for the order they are written in.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// for (filepath, data) in gob.iter() {
+    ///     assert_eq!(filepath, &PathBuf::from("foo.bar"));
+    /// 
+    ///     assert_eq!(data, b"foobar");
+    /// }
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.files.iter())
+    }
+
+    /// Gets the sum of the sizes of the data of all files in bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// assert_eq!(gob.total_size(), 14);
+    /// ```
+    pub fn total_size(&self) -> u64 {
+        self.files.values().map(|data| data.len() as u64).sum()
+    }
+
+    /// Gets the size in bytes of the binary representation of the archive, i.e.
+    /// of the header, the file definitions, the data of the files and the
+    /// padding between them.
+    /// 
+    /// The size is computed like in [`Gob::check_packable`], so it takes
+    /// alignment, deduplication, a captured raw layout and compression into
+    /// account. If the archive cannot be written, e.g. because the result
+    /// exceeds [`u32::MAX`], the size is estimated as if the data of each file
+    /// were written separately and uncompressed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     gob.set_data_alignment(4);
+    /// 
+    ///     assert_eq!(gob.packed_size(), gob.as_bytes()?.len() as u64);
+    /// 
+    ///     gob.files.insert(PathBuf::from("a.b"), b"foobar".to_vec());
+    /// 
+    ///     gob.set_deduplicating(true);
+    /// 
+    ///     assert_eq!(gob.packed_size(), gob.as_bytes()?.len() as u64);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn packed_size(&self) -> u64 {
+        match self.plan() {
+            Ok(plan) => plan.size,
+            Err(_) => self.estimated_size(),
+        }
+    }
+
+    /// Estimates the size in bytes of the binary representation of the archive
+    /// without encoding it, as if the data of each file were written separately
+    /// and uncompressed.
+    fn estimated_size(&self) -> u64 {
+        let definitions_len = (8 + self.format.filepath_len() as u64) * self.files.len() as u64;
+
+        let mut file_data_offset: u64 = match self.format {
+            GobFormat::DarkForces => 8,
+            GobFormat::JediKnight => self.body_offset.max(12) as u64 + 4 + definitions_len,
+        };
+
+        for (_, file_data) in self.entries() {
+            file_data_offset = file_data_offset.next_multiple_of(self.data_alignment.max(1) as u64);
+
+            file_data_offset += file_data.len() as u64;
+        }
+
+        let size = match self.format {
+            GobFormat::DarkForces => file_data_offset + 4 + definitions_len,
+            GobFormat::JediKnight => file_data_offset,
+        };
+
+        size.max(self.padded_size.unwrap_or(0))
+    }
+
+    /// Gets the filepath of each file together with the offset and the size of
+    /// its data within the binary representation of the archive, in the order
+    /// the files are written in.
+    /// 
+    /// The offsets are computed the same way as when writing the archive, e.g.
+    /// with [`Gob::as_bytes`], so they take alignment, deduplication, a
+    /// captured raw layout and compression into account. Fails if the archive
+    /// cannot be written.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let layout = gob.layout()?;
+    /// 
+    ///     assert_eq!(layout[0], (PathBuf::from("foo.bar"), 16 + 2 * 136, 6));
+    /// 
+    ///     for (filepath, offset, size) in layout {
+    ///         let range = offset as usize..(offset + size) as usize;
+    /// 
+    ///         assert_eq!(gob.get(&filepath), Some(&data[range]));
+    ///     }
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn layout(&self) -> Result<Vec<(PathBuf, u64, u64)>, GobError> {
+        self.plan().map(|plan| plan.entries)
+    }
+
+    /// Checks whether the archive can be written like [`Gob::validate`] and, if
+    /// so, computes what writing it would produce, without producing it.
+    /// 
+    /// The returned [`PackPlan`] holds the exact size of the binary
+    /// representation of the archive and the layout of its files as given by
+    /// [`Gob::layout`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"foobar".to_vec());
+    /// 
+    ///     gob.set_deduplicating(true);
+    /// 
+    ///     let plan = gob.check_packable().unwrap();
+    /// 
+    ///     assert_eq!(plan.size, gob.as_bytes()?.len() as u64);
+    /// 
+    ///     assert_eq!(plan.entries[0].1, plan.entries[1].1);
+    /// 
+    ///     gob.files.insert(PathBuf::from("a".repeat(200)), b"a".to_vec());
+    /// 
+    ///     let errors = gob.check_packable().unwrap_err();
+    /// 
+    ///     assert!(matches!(errors[..], [GobError::FilepathTooLong { .. }]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn check_packable(&self) -> Result<PackPlan, Vec<GobError>> {
+        self.validate()?;
+
+        self.plan().map_err(|error| vec![error])
+    }
+
+    /// Computes the size of the binary representation of the archive and the
+    /// layout of its files the same way as when writing the archive.
+    fn plan(&self) -> Result<PackPlan, GobError> {
+        #[cfg(feature = "compression")]
+        if self.compression != CompressionCodec::None {
+            return self.compressed()?.plan();
+        }
+
+        if let Some(layout) = self.fitting_raw_layout() {
+            return Ok(PackPlan {
+                size: layout.length,
+                entries: layout.definitions
+                    .iter()
+                    .map(|file_definition| (file_definition.filepath.clone(), file_definition.offset, file_definition.size))
+                    .collect(),
+            });
+        }
+
+        let entries = self.entries();
+
+        let table = self.encode_table(&entries)?;
+
+        let data_size: u64 = entries
+            .iter()
+            .zip(&table.padding)
+            .filter_map(|(&(_, file_data), padding)| padding.map(|padding| padding as u64 + file_data.len() as u64))
+            .sum();
+
+        Ok(PackPlan {
+            size: table.head.len() as u64 + data_size + table.tail.len() as u64,
+            entries: entries
+                .iter()
+                .zip(table.offsets)
+                .map(|(&(filepath, file_data), offset)| (filepath.clone(), offset as u64, file_data.len() as u64))
+                .collect(),
+        })
+    }
+
+    /// Iterates over the filepaths of the files and the sizes of their data in
+    /// bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// let sizes: Vec<(&PathBuf, usize)> = gob.sizes().collect();
+    /// 
+    /// assert_eq!(sizes, [(&PathBuf::from("foo.bar"), 6)]);
+    /// ```
+    pub fn sizes(&self) -> impl Iterator<Item = (&PathBuf, usize)> {
+        self.files.iter().map(|(filepath, data)| (filepath, data.len()))
+    }
+
+    /// Gets the data of the file at a given path within the archive.
+    /// 
+    /// The path may be separated by slashes or backslashes. If the archive is
+    /// case-insensitive (see [`Gob::set_case_insensitive`]), a file whose
+    /// filepath matches exactly is preferred over one only matching when
+    /// ignoring case.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("fizz").join("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert_eq!(gob.get("fizz/foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    /// assert_eq!(gob.get("fizz\\foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    /// assert_eq!(gob.get("foo.bar"), None);
+    /// ```
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        self.position(path.as_ref()).map(|i| self.files[i].as_slice())
+    }
+
+    /// Gets the data of the file at a given path within the archive, ignoring
+    /// ASCII case regardless of whether the archive is case-insensitive (see
+    /// [`Gob::set_case_insensitive`]).
+    /// 
+    /// A file whose filepath matches exactly is preferred; otherwise, the first
+    /// matching file in the order of [`Gob::files`] is returned. Filepaths are
+    /// compared component by component without allocating lowercased copies.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/Foo.BM"), b"Foo".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/foo.bm"), b"foo".to_vec());
+    /// 
+    /// assert_eq!(gob.get("MISSION/FOO.BM"), None);
+    /// 
+    /// assert_eq!(gob.get_ignore_ascii_case("MISSION/FOO.BM"), Some(&b"Foo"[..]));
+    /// 
+    /// assert_eq!(gob.get_ignore_ascii_case("mission\\foo.bm"), Some(&b"foo"[..]));
+    /// ```
+    pub fn get_ignore_ascii_case(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        self.position_with_case(path.as_ref(), true).map(|i| self.files[i].as_slice())
+    }
+
+    /// Gets the data of the file at a given path within the archive mutably, for
+    /// editing it in place.
+    /// 
+    /// The path is matched like in [`Gob::get`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    /// if let Some(data) = gob.get_mut("mission\\foo.bar") {
+    ///     data.push(b'\n');
+    /// }
+    /// 
+    /// assert_eq!(gob.get("mission/foo.bar"), Some(&b"foobar\n"[..]));
+    /// 
+    /// assert_eq!(gob.get_mut("foo.bar"), None);
+    /// ```
+    pub fn get_mut(&mut self, path: impl AsRef<Path>) -> Option<&mut Vec<u8>> {
+        self.position(path.as_ref()).map(|i| &mut self.files[i])
+    }
+
+    /// Gets a reader over the data of the file at a given path within the
+    /// archive, for passing it to consumers of streams without copying it.
+    /// 
+    /// The path is matched like in [`Gob::get`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Read, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     let mut data = String::new();
+    /// 
+    ///     gob.reader("foo.bar").unwrap().read_to_string(&mut data)?;
+    /// 
+    ///     assert_eq!(data, "foobar");
+    /// 
+    ///     assert!(gob.reader("fizz.buzz").is_none());
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn reader(&self, path: impl AsRef<Path>) -> Option<impl Read + Seek + '_> {
+        self.get(path).map(Cursor::new)
+    }
+
+    /// Checks whether the archive contains a file at a given path.
+    /// 
+    /// The path may be separated by slashes or backslashes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(
+    ///     PathBuf::from("fizz").join("foo.bar"),
+    ///     b"foobar".to_vec(),
+    /// );
+    /// 
+    /// assert!(gob.contains("fizz\\foo.bar"));
+    /// 
+    /// assert!(!gob.contains("foo.bar"));
+    /// ```
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        self.get(path).is_some()
+    }
+
+    /// Computes the CRC-32 checksum of the data of the file at a given path
+    /// within the archive.
+    /// 
+    /// The path is matched like in [`Gob::get`]. Returns `None` if the archive
+    /// does not contain the file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"123456789".to_vec());
+    /// 
+    /// assert_eq!(gob.crc32("foo.bar"), Some(0xCBF43926));
+    /// 
+    /// assert_eq!(gob.crc32("fizz.buzz"), None);
+    /// ```
+    pub fn crc32(&self, path: impl AsRef<Path>) -> Option<u32> {
+        self.get(path).map(crc::crc32)
+    }
+
+    /// Computes the CRC-32 checksums of the data of all files, keyed by their
+    /// filepaths.
+    /// 
+    /// # Examples
+    /// 
+    /// Detects which files changed between two versions of an archive:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut old = Gob::new();
+    /// 
+    /// old.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// old.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let mut new = Gob::new();
+    /// 
+    /// new.files.insert(PathBuf::from("foo.bar"), b"foobaz".to_vec());
+    /// 
+    /// new.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let old_hashes = old.hashes();
+    /// 
+    /// let changed: Vec<PathBuf> = new
+    ///     .hashes()
+    ///     .into_iter()
+    ///     .filter(|(filepath, crc)| old_hashes.get(filepath) != Some(crc))
+    ///     .map(|(filepath, _)| filepath)
+    ///     .collect();
+    /// 
+    /// assert_eq!(changed, [PathBuf::from("foo.bar")]);
+    /// ```
+    pub fn hashes(&self) -> HashMap<PathBuf, u32> {
+        self.files
+            .iter()
+            .map(|(filepath, data)| (filepath.clone(), crc::crc32(data)))
+            .collect()
+    }
+
+    /// Computes a CRC-32 checksum over all files of the archive, covering their
+    /// filepaths and the checksums of their data.
+    /// 
+    /// The files are folded in the order of [`Gob::iter_sorted`], so the result
+    /// neither depends on the order the files were inserted in nor on whether
+    /// filepaths are separated by slashes or backslashes. Like [`Gob::hashes`],
+    /// this is not part of the GOB format.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut a = Gob::new();
+    /// 
+    /// a.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// a.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let mut b = Gob::new();
+    /// 
+    /// b.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// b.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// assert_eq!(a.archive_checksum(), b.archive_checksum());
+    /// 
+    /// b.files.insert(PathBuf::from("foo.bar"), b"foobaz".to_vec());
+    /// 
+    /// assert_ne!(a.archive_checksum(), b.archive_checksum());
+    /// ```
+    pub fn archive_checksum(&self) -> u32 {
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for (filepath, data) in self.iter_sorted() {
+            let filepath = path::to_gob_bytes(&path::normalize(filepath), FilenameEncoding::Utf8)
+                .expect("Filepath should be representable as UTF-8");
+
+            bytes.extend(filepath);
+
+            bytes.push(0);
+
+            bytes.extend(crc::crc32(data).to_le_bytes());
+        }
+
+        crc::crc32(&bytes)
+    }
+
+    /// Gets the metadata of the source file of the file at a given path within
+    /// the archive, as captured when packing a directory with
+    /// [`DirectoryOptions::metadata`].
+    /// 
+    /// The path may be separated by slashes or backslashes. Returns `None` if no
+    /// metadata was captured for the file. The metadata is not part of the GOB
+    /// format, so it is neither written nor parsed.
+    /// 
+    /// # Examples
+    /// 
+    /// Only repacks files which changed since the last run:
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, options::DirectoryOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_metadata");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(&path)?;
+    /// 
+    ///     std::fs::write(path.join("foo.bar"), b"foobar")?;
+    /// 
+    ///     let options = DirectoryOptions::new().metadata(true);
+    /// 
+    ///     let gob = Gob::from_directory_with_options(&path, options)?;
+    /// 
+    ///     let metadata = gob.metadata("foo.bar").unwrap();
+    /// 
+    ///     assert_eq!(metadata.size, 6);
+    /// 
+    ///     let modified = std::fs::metadata(path.join("foo.bar"))?.modified().ok();
+    /// 
+    ///     assert_eq!(metadata.modified, modified);
+    /// 
+    ///     assert!(Gob::from_directory(&path)?.metadata("foo.bar").is_none());
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn metadata(&self, path: impl AsRef<Path>) -> Option<&EntryMeta> {
+        self.metadata.get(&path::normalize(path.as_ref()))
+    }
+
+    /// Iterates over the filepaths of the files, in the order of [`Gob::files`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let filepaths: Vec<&PathBuf> = gob.paths().collect();
+    /// 
+    /// assert_eq!(filepaths, ["foo.bar", "fizz.buzz"]);
+    /// ```
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.keys()
+    }
+
+    /// Iterates over the files sorted by their filepaths, ignoring ASCII case
+    /// first and breaking ties by case, like when writing deterministically
+    /// (see [`Gob::set_deterministic`]).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("Foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("a.b"), b"ab".to_vec());
+    /// 
+    /// let filepaths: Vec<&PathBuf> = gob.iter_sorted().map(|(filepath, _)| filepath).collect();
+    /// 
+    /// assert_eq!(filepaths, ["a.b", "fizz.buzz", "Foo.bar"]);
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&PathBuf, &Vec<u8>)> {
+        let mut entries: Vec<Entry<'_>> = self.files.iter().collect();
+
+        entries.sort_by_cached_key(|(filepath, _)| path::sort_key(filepath));
+
+        entries.into_iter()
+    }
+
+    /// Iterates over the files in the order they are written in by
+    /// [`Gob::as_bytes`], borrowing their filepaths and data.
+    /// 
+    /// This is the order of [`Gob::files`], or, if the archive is written
+    /// deterministically (see [`Gob::set_deterministic`]), the order of
+    /// [`Gob::iter_sorted`]. The data is yielded as held by the archive object,
+    /// i.e. before any compression is applied when writing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     gob.set_deterministic(true);
+    /// 
+    ///     let filepaths: Vec<&Path> = gob.entries_ordered().map(|(filepath, _)| filepath).collect();
+    /// 
+    ///     assert_eq!(filepaths, [Path::new("fizz.buzz"), Path::new("foo.bar")]);
+    /// 
+    ///     let parsed = Gob::from_bytes(&gob.as_bytes()?)?;
+    /// 
+    ///     assert!(gob.entries_ordered().eq(parsed.entries_ordered()));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn entries_ordered(&self) -> impl Iterator<Item = (&Path, &[u8])> {
+        self.entries()
+            .into_iter()
+            .map(|(filepath, data)| (filepath.as_path(), data.as_slice()))
+    }
+
+    /// Consumes the [`Gob`] object, returning its files in a [`BTreeMap`],
+    /// ordered by their filepaths.
+    /// 
+    /// Unlike [`Gob::iter_sorted`], the files are ordered by the [`Ord`]
+    /// implementation of [`PathBuf`], i.e. by component and respecting case.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("a.b"), b"ab".to_vec());
+    /// 
+    /// let files = gob.into_btree();
+    /// 
+    /// let filepaths: Vec<&PathBuf> = files.keys().collect();
+    /// 
+    /// assert_eq!(filepaths, ["a.b", "fizz.buzz", "foo.bar"]);
+    /// ```
+    pub fn into_btree(self) -> BTreeMap<PathBuf, Vec<u8>> {
+        self.files.into_iter().collect()
+    }
+
+    /// Iterates over the files whose filepaths have a given extension, ignoring
+    /// ASCII case, in the order of [`Gob::files`].
+    /// 
+    /// A leading dot of the extension is ignored, so `".mat"` and `"MAT"` are
+    /// equivalent. Files without an extension, such as `README`, are only
+    /// matched by an empty extension. Only the last extension is considered, so
+    /// `foo.tar.gz` has the extension `gz`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mat/foo.MAT"), b"foo".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("3do/bar.3do"), b"bar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("README"), b"readme".to_vec());
+    /// 
+    /// let filepaths: Vec<&PathBuf> = gob.by_extension(".mat").map(|(filepath, _)| filepath).collect();
+    /// 
+    /// assert_eq!(filepaths, [&PathBuf::from("mat/foo.MAT")]);
+    /// 
+    /// assert_eq!(gob.by_extension("").count(), 1);
+    /// ```
+    pub fn by_extension<'a>(&'a self, extension: &str) -> impl Iterator<Item = (&'a PathBuf, &'a Vec<u8>)> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension).to_owned();
+
+        self.files.iter().filter(move |(filepath, _)| match filepath.extension() {
+            Some(file_extension) => file_extension.eq_ignore_ascii_case(&extension),
+            None => extension.is_empty(),
+        })
+    }
+
+    /// Iterates over the files whose filepaths match a given glob pattern.
+    /// 
+    /// Patterns are matched ignoring ASCII case, like paths within GOB archives
+    /// were treated by DOS. The following syntax is supported:
+    /// 
+    /// - `?` matches any single character except a separator.
+    /// - `*` matches any sequence of characters except separators.
+    /// - `**` matches any sequence of characters, including separators; `**\\`
+    ///   or `**/` also matches no component at all.
+    /// - Separators may be written as slashes or backslashes.
+    /// - Any other character matches itself.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/01nar.lvl"), b"foo".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/01nar.pal"), b"bar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("02kas.lvl"), b"baz".to_vec());
+    /// 
+    /// let levels: Vec<&PathBuf> = gob.matching("**\\*.LVL").map(|(filepath, _)| filepath).collect();
+    /// 
+    /// assert_eq!(levels, ["mission/01nar.lvl", "02kas.lvl"]);
+    /// 
+    /// let mission: Vec<&PathBuf> = gob.matching("MISSION/*").map(|(filepath, _)| filepath).collect();
+    /// 
+    /// assert_eq!(mission, ["mission/01nar.lvl", "mission/01nar.pal"]);
+    /// ```
+    pub fn matching<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = (&'a PathBuf, &'a Vec<u8>)> {
+        self.files
+            .iter()
+            .filter(move |(filepath, _)| path::matches_glob(pattern, filepath))
+    }
+
+    /// Removes the file at a given path from the archive, returning its data.
+    /// 
+    /// The path is matched like in [`Gob::get`]. The order of the remaining
+    /// files is preserved. Returns `None` if the archive does not contain the
+    /// file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    /// assert_eq!(gob.remove("mission\\foo.bar"), Some(b"foobar".to_vec()));
+    /// 
+    /// assert_eq!(gob.remove("mission\\foo.bar"), None);
+    /// 
+    /// assert!(gob.is_empty());
+    /// ```
+    pub fn remove(&mut self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        let i = self.position(path.as_ref())?;
+
+        self.files.shift_remove_index(i).map(|(_, data)| data)
+    }
+
+    /// Creates a new archive object from the files at given paths within the
+    /// current archive object, keeping its settings.
+    /// 
+    /// The paths are matched like in [`Gob::get`]; paths the archive does not
+    /// contain are skipped. Files keep the order of the given paths.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("a.b"), b"ab".to_vec());
+    /// 
+    /// let subset = gob.subset(["a.b", "foo.bar", "foo.baz"]);
+    /// 
+    /// let filepaths: Vec<&PathBuf> = subset.files.keys().collect();
+    /// 
+    /// assert_eq!(filepaths, ["a.b", "foo.bar"]);
+    /// ```
+    pub fn subset<I, P>(&self, paths: I) -> Gob
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut files = GobMap::new();
+
+        let mut metadata = HashMap::new();
+
+        for path in paths {
+            let i = match self.position(path.as_ref()) {
+                Some(i) => i,
+                None => continue,
+            };
+
+            let (filepath, data) = self.files.get_index(i).expect("Index should be in range");
+
+            if let Some(entry_meta) = self.metadata.get(filepath) {
+                metadata.insert(filepath.clone(), *entry_meta);
+            }
+
+            files.insert(filepath.clone(), data.clone());
+        }
+
+        Gob {
+            files,
+            metadata,
+            raw_layout: None,
+            ..*self
+        }
+    }
+
+    /// Creates a copy of the archive with an additional file at a given path
+    /// listing the filepaths of all other files, for inspection by external
+    /// tools.
+    /// 
+    /// The listing contains one filepath per line, each separated by
+    /// backslashes like within the archive and followed by a newline, in the
+    /// order the files are written in. The listing is encoded as UTF-8 and
+    /// appended as the last file, replacing any file already at the path.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let gob = gob.with_manifest("manifest.txt");
+    /// 
+    /// assert_eq!(gob.get("manifest.txt"), Some(&b"mission\\foo.bar\nfizz.buzz\n"[..]));
+    /// 
+    /// assert_eq!(gob.len(), 3);
+    /// ```
+    pub fn with_manifest(&self, manifest_path: &str) -> Gob {
+        let mut gob = self.clone();
+
+        let manifest_path = path::normalize(Path::new(manifest_path));
+
+        gob.remove(&manifest_path);
+
+        gob.metadata.remove(&manifest_path);
+
+        let mut manifest: Vec<u8> = Vec::new();
+
+        for (filepath, _) in gob.entries() {
+            let filepath_bytes = path::to_gob_bytes(filepath, FilenameEncoding::Utf8)
+                .expect("Filepath should be representable in UTF-8");
+
+            manifest.extend(filepath_bytes);
+
+            manifest.push(b'\n');
+        }
+
+        gob.files.insert(manifest_path, manifest);
+
+        gob
+    }
+
+    /// Splits the archive into volumes whose binary representations each take up
+    /// at most a given amount of bytes, as given by [`Gob::packed_size`],
+    /// keeping the settings of the archive.
+    /// 
+    /// The files are distributed over the volumes in the order they are written
+    /// in, filling each volume before starting the next one, so every file is
+    /// contained in exactly one volume. Fails if a file does not fit into a
+    /// volume on its own, or if a volume cannot be written. An archive without
+    /// files is split into no volumes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), vec![0; 100]);
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), vec![1; 100]);
+    /// 
+    ///     gob.files.insert(PathBuf::from("a.b"), vec![2; 100]);
+    /// 
+    ///     let volumes = gob.split(512)?;
+    /// 
+    ///     assert_eq!(volumes.len(), 2);
+    /// 
+    ///     assert!(volumes.iter().all(|volume| volume.packed_size() <= 512));
+    /// 
+    ///     assert_eq!(volumes[0].paths().collect::<Vec<_>>(), ["foo.bar", "fizz.buzz"]);
+    /// 
+    ///     assert_eq!(volumes[1].paths().collect::<Vec<_>>(), ["a.b"]);
+    /// 
+    ///     assert!(matches!(gob.split(128), Err(GobError::FileTooLarge { .. })));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Volumes are sized like they are written, e.g. sharing identical data if
+    /// deduplicating:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     for filepath in ["foo.bar", "fizz.buzz", "a.b"] {
+    ///         gob.files.insert(PathBuf::from(filepath), vec![0; 100]);
+    ///     }
+    /// 
+    ///     assert_eq!(gob.split(600)?.len(), 2);
+    /// 
+    ///     gob.set_deduplicating(true);
+    /// 
+    ///     let volumes = gob.split(600)?;
+    /// 
+    ///     assert_eq!(volumes.len(), 1);
+    /// 
+    ///     assert_eq!(volumes[0].as_bytes()?.len(), 16 + 3 * 136 + 100);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn split(&self, max_bytes: u64) -> Result<Vec<Gob>, GobError> {
+        let base_size: u64 = match self.format {
+            GobFormat::DarkForces => 8 + 4,
+            GobFormat::JediKnight => self.body_offset.max(12) as u64 + 4,
+        };
+
+        let definition_len = 8 + self.format.filepath_len() as u64;
+
+        // The most padding aligning the data of a file may take up.
+        let slack = self.data_alignment.max(1) as u64 - 1;
+
+        let mut volumes = Vec::new();
+
+        let mut volume = self.emptied();
+
+        // An upper bound of the size of the volume, kept as files are added.
+        let mut volume_size = base_size;
+
+        let mut stored: HashSet<&[u8]> = HashSet::new();
+
+        for (filepath, data) in self.entries() {
+            let unshared_size = definition_len + self.stored_len(data)? + slack;
+
+            let mut file_size = match self.deduplicating && stored.contains(data.as_slice()) {
+                true => definition_len,
+                false => unshared_size,
+            };
+
+            volume.files.insert(filepath.clone(), data.clone());
+
+            if !volume.fits(volume_size + file_size, max_bytes)? && volume.len() > 1 {
+                volume.files.pop();
+
+                volumes.push(mem::replace(&mut volume, self.emptied()));
+
+                volume.files.insert(filepath.clone(), data.clone());
+
+                volume_size = base_size;
+
+                stored.clear();
+
+                file_size = unshared_size;
+            }
+
+            if !volume.fits(volume_size + file_size, max_bytes)? {
+                return Err(GobError::FileTooLarge {
+                    path: filepath.clone(),
+                    size: volume.packed_size(),
+                });
+            }
+
+            volume_size += file_size;
+
+            stored.insert(data);
+
+            if let Some(entry_meta) = self.metadata.get(filepath) {
+                volume.metadata.insert(filepath.clone(), *entry_meta);
+            }
+        }
+
+        if !volume.is_empty() {
+            volumes.push(volume);
+        }
+
+        Ok(volumes)
+    }
+
+    /// Checks whether the binary representation of the archive takes up at
+    /// most a given amount of bytes, given an upper bound of its size before
+    /// padding it, and only computing the exact size if the bound exceeds it.
+    fn fits(&self, size_bound: u64, max_bytes: u64) -> Result<bool, GobError> {
+        let padded = self.padded_size.is_none_or(|padded_size| size_bound <= padded_size);
+
+        if padded && size_bound.max(self.padded_size.unwrap_or(0)) <= max_bytes {
+            return Ok(true);
+        }
+
+        match self.plan() {
+            Ok(plan) => Ok(plan.size <= max_bytes),
+            Err(GobError::ExceedsPaddedSize { .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Gets the size in bytes the data of a file takes up when written.
+    fn stored_len(&self, data: &[u8]) -> Result<u64, GobError> {
+        #[cfg(feature = "compression")]
+        if self.compression != CompressionCodec::None {
+            return Ok(self.compression.compress(data)?.len() as u64);
+        }
+
+        Ok(data.len() as u64)
+    }
+
+    /// Inserts a file at a given path into the archive, checking up front that it
+    /// can be written, unlike inserting into [`Gob::files`] directly.
+    /// 
+    /// The path is normalized to the separator of the platform. Fails if the
+    /// path is absolute or contains `..` components, if it is unrepresentable in
+    /// the filename encoding, if it does not fit into the filepath field of the
+    /// archive, or, if the archive is case-insensitive, if another file has the
+    /// same path in a different case. Returns the data of the file replaced at
+    /// the same path, if any.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     assert_eq!(gob.insert_checked(PathBuf::from("foo.bar"), b"foobar".to_vec())?, None);
+    /// 
+    ///     assert_eq!(gob.insert_checked(PathBuf::from("foo.bar"), b"foobaz".to_vec())?, Some(b"foobar".to_vec()));
+    /// 
+    ///     assert!(matches!(
+    ///         gob.insert_checked(PathBuf::from("a".repeat(128)), b"a".to_vec()),
+    ///         Err(GobError::FilepathTooLong { len: 128, max: 127, .. }),
+    ///     ));
+    /// 
+    ///     assert!(matches!(
+    ///         gob.insert_checked(PathBuf::from("/etc/passwd"), b"foobar".to_vec()),
+    ///         Err(GobError::UnsafePath(_)),
+    ///     ));
+    /// 
+    ///     assert!(matches!(
+    ///         gob.insert_checked(PathBuf::from("..\\..\\secret"), b"foobar".to_vec()),
+    ///         Err(GobError::UnsafePath(_)),
+    ///     ));
+    /// 
+    ///     for path in ["C:\\evil.dll", "\\evil.dll", "mission\\D:evil.dll"] {
+    ///         assert!(matches!(
+    ///             gob.insert_checked(PathBuf::from(path), b"foobar".to_vec()),
+    ///             Err(GobError::UnsafePath(_)),
+    ///         ));
+    ///     }
+    /// 
+    ///     gob.set_case_insensitive(true);
+    /// 
+    ///     assert!(matches!(
+    ///         gob.insert_checked(PathBuf::from("FOO.BAR"), b"FOOBAR".to_vec()),
+    ///         Err(GobError::DuplicateFilepath(_)),
+    ///     ));
+    /// 
+    ///     assert_eq!(gob.len(), 1);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn insert_checked(&mut self, path: PathBuf, data: Vec<u8>) -> Result<Option<Vec<u8>>, GobError> {
+        Self::encode_filepath(&path, self.filename_encoding, self.format)?;
+
+        let path = path::normalize(&path);
+
+        if !self.files.contains_key(&path) && self.position(&path).is_some() {
+            return Err(GobError::DuplicateFilepath(path));
+        }
+
+        Ok(self.files.insert(path, data))
+    }
+
+    /// Renames the file at a given path within the archive, keeping its
+    /// position.
+    /// 
+    /// The current path is matched like in [`Gob::get`], and the new path is
+    /// normalized to the separator of the platform. Fails if the archive does not
+    /// contain the file, if another file already has the new path, if the new
+    /// path is absolute or contains `..` components, or if the new path does not
+    /// fit into the filepath field of the archive.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, error::GobError};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     gob.rename("foo.bar", "mission\\foo.bar")?;
+    /// 
+    ///     assert_eq!(gob.get("mission/foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     assert!(matches!(
+    ///         gob.rename("mission/foo.bar", "fizz.buzz"),
+    ///         Err(GobError::DuplicateFilepath(_)),
+    ///     ));
+    /// 
+    ///     assert!(matches!(
+    ///         gob.rename("foo.bar", "foo.baz"),
+    ///         Err(GobError::FileNotFound(_)),
+    ///     ));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn rename(&mut self, from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<(), GobError> {
+        let i = match self.position(from.as_ref()) {
+            Some(i) => i,
+            None => {
+                return Err(GobError::FileNotFound(from.as_ref().to_path_buf()));
+            }
+        };
+
+        Self::encode_filepath(to.as_ref(), self.filename_encoding, self.format)?;
+
+        let to = path::normalize(to.as_ref());
+
+        if self.position(&to).is_some_and(|j| j != i) {
+            return Err(GobError::DuplicateFilepath(to));
+        }
+
+        let (_, data) = self.files.shift_remove_index(i).expect("Index should be in range");
+
+        self.files.shift_insert(i, to, data);
+
+        Ok(())
+    }
+
+    /// Retains only the files for which a given predicate returns `true`,
+    /// preserving their order.
+    /// 
+    /// # Examples
+    /// 
+    /// Strips all models from an archive:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.3do"), b"foo".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("bar.mat"), b"bar".to_vec());
+    /// 
+    /// gob.retain(|filepath, _| filepath.extension() != Some("3do".as_ref()));
+    /// 
+    /// assert!(!gob.contains("foo.3do"));
+    /// 
+    /// assert!(gob.contains("bar.mat"));
     /// ```
-    pub fn new() -> Self {
-        let files = GobMap::new();
+    pub fn retain<F: FnMut(&Path, &[u8]) -> bool>(&mut self, mut f: F) {
+        self.files.retain(|filepath, data| f(filepath, data));
+    }
+
+    /// Replaces the data of each file with the result of a given closure, passed
+    /// the filepath and the data of the file, preserving the filepaths and their
+    /// order.
+    /// 
+    /// # Examples
+    /// 
+    /// Patches a byte signature across all files:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), b"foobuzz".to_vec());
+    /// 
+    /// gob.map_data(|_, mut data| {
+    ///     data[..3].copy_from_slice(b"FOO");
+    /// 
+    ///     data
+    /// });
+    /// 
+    /// assert_eq!(gob.get("foo.bar"), Some(&b"FOObar"[..]));
+    /// 
+    /// assert_eq!(gob.get("fizz.buzz"), Some(&b"FOObuzz"[..]));
+    /// ```
+    pub fn map_data<F: FnMut(&Path, Vec<u8>) -> Vec<u8>>(&mut self, mut f: F) {
+        for (filepath, data) in self.files.iter_mut() {
+            *data = f(filepath, std::mem::take(data));
+        }
+    }
+
+    /// Replaces the data of each file with the result of a given fallible
+    /// closure, passed the filepath and the data of the file.
+    /// 
+    /// If the closure fails for any file, its error is returned and the archive
+    /// is left unchanged.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// let mut gob = Gob::new();
+    /// 
+    /// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// gob.files.insert(PathBuf::from("fizz.buzz"), vec![]);
+    /// 
+    /// let result = gob.try_map_data(|filepath, data| {
+    ///     if data.is_empty() {
+    ///         Err(format!("{} is empty", filepath.display()))
+    ///     } else {
+    ///         Ok(data.to_ascii_uppercase())
+    ///     }
+    /// });
+    /// 
+    /// assert_eq!(result, Err(String::from("fizz.buzz is empty")));
+    /// 
+    /// assert_eq!(gob.get("foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    /// gob.files.shift_remove(&PathBuf::from("fizz.buzz"));
+    /// 
+    /// assert!(gob.try_map_data(|_, data| Ok::<_, String>(data.to_ascii_uppercase())).is_ok());
+    /// 
+    /// assert_eq!(gob.get("foo.bar"), Some(&b"FOOBAR"[..]));
+    /// ```
+    pub fn try_map_data<E, F: FnMut(&Path, &[u8]) -> Result<Vec<u8>, E>>(&mut self, mut f: F) -> Result<(), E> {
+        // Mapped completely before replacing anything, so a failure changes nothing.
+        let mut mapped: Vec<Vec<u8>> = Vec::with_capacity(self.files.len());
+
+        for (filepath, data) in &self.files {
+            mapped.push(f(filepath, data)?);
+        }
+
+        for (data, mapped) in self.files.values_mut().zip(mapped) {
+            *data = mapped;
+        }
+
+        Ok(())
+    }
+
+    /// Compares the current archive object with another archive, listing the files
+    /// which were added, removed, or modified in the other archive.
+    /// 
+    /// Filepaths are compared like in [`Gob::get`]. The current archive determines
+    /// the order and the filepaths of removed and modified files, the other
+    /// archive the order and the filepaths of added files.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, diff::Modified};
+    /// 
+    /// let mut old = Gob::new();
+    /// 
+    /// old.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// old.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let mut new = Gob::new();
+    /// 
+    /// new.files.insert(PathBuf::from("foo.bar"), b"foobarbaz".to_vec());
+    /// 
+    /// new.files.insert(PathBuf::from("a.b"), b"ab".to_vec());
+    /// 
+    /// let diff = old.diff(&new);
+    /// 
+    /// assert_eq!(diff.added, [&PathBuf::from("a.b")]);
+    /// 
+    /// assert_eq!(diff.removed, [&PathBuf::from("fizz.buzz")]);
+    /// 
+    /// assert_eq!(diff.modified, [Modified {
+    ///     path: &PathBuf::from("foo.bar"),
+    ///     size_delta: 3,
+    /// }]);
+    /// ```
+    pub fn diff<'a>(&'a self, other: &'a Gob) -> GobDiff<'a> {
+        let mut diff = GobDiff::default();
+
+        for (filepath, data) in &self.files {
+            match other.position(filepath) {
+                Some(i) => {
+                    if other.files[i] != *data {
+                        diff.modified.push(Modified {
+                            path: filepath,
+                            size_delta: other.files[i].len() as i64 - data.len() as i64,
+                        });
+                    }
+                }
+                None => diff.removed.push(filepath),
+            }
+        }
+
+        for filepath in other.files.keys() {
+            if self.position(filepath).is_none() {
+                diff.added.push(filepath);
+            }
+        }
+
+        diff
+    }
+
+    /// Checks whether the current archive object contains the same files with
+    /// the same data as another archive, regardless of their order and of the
+    /// separators of their filepaths.
+    /// 
+    /// Unlike comparing the results of [`Gob::as_bytes`], this ignores how the
+    /// archives are laid out when written, such as the order of the files, the
+    /// variant of the format or the alignment of the data. Unlike `==`, it also
+    /// ignores whether filepaths are separated by slashes or backslashes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut a = Gob::new();
+    /// 
+    ///     a.files.insert(PathBuf::from("mission/foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     a.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut b = Gob::new();
+    /// 
+    ///     b.set_data_alignment(4);
+    /// 
+    ///     b.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     b.files.insert(PathBuf::from("mission\\foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     assert!(a.content_eq(&b));
+    /// 
+    ///     assert_ne!(a.as_bytes()?, b.as_bytes()?);
+    /// 
+    ///     b.files.insert(PathBuf::from("fizz.buzz"), b"FIZZBUZZ".to_vec());
+    /// 
+    ///     assert!(!a.content_eq(&b));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn content_eq(&self, other: &Gob) -> bool {
+        fn normalized(gob: &Gob) -> HashMap<PathBuf, &[u8]> {
+            gob.files
+                .iter()
+                .map(|(filepath, data)| (path::normalize(filepath), data.as_slice()))
+                .collect()
+        }
+
+        self.files.len() == other.files.len() && normalized(self) == normalized(other)
+    }
+
+    /// Checks whether a GOB file at a given [`Path`] holds the files of the
+    /// current archive object, e.g. to skip repacking an archive which is
+    /// already up to date.
+    /// 
+    /// The variant of the format, the amount of files and their filepaths and
+    /// sizes are compared first, in the order the files are written in, reading
+    /// only the file definitions. Only if these match and `compare_data` is
+    /// `true`, the data of the files is read and compared as well. Other details
+    /// of the layout, such as the alignment, are not compared.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_matches_file.gob");
+    /// 
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     assert!(gob.matches_file(&path, true)?);
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"FOOBAR".to_vec());
+    /// 
+    ///     assert!(gob.matches_file(&path, false)?);
+    /// 
+    ///     assert!(!gob.matches_file(&path, true)?);
+    /// 
+    ///     gob.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     assert!(!gob.matches_file(&path, false)?);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn matches_file(&self, path: &Path, compare_data: bool) -> Result<bool, GobError> {
+        #[cfg(feature = "compression")]
+        if self.compression != CompressionCodec::None {
+            return self.compressed()?.matches_file(path, compare_data);
+        }
+
+        let mut reader = io::BufReader::new(fs::File::open(path)?);
+
+        let ParsedTable {
+            format,
+            file_definitions,
+            ..
+        } = Self::read_file_definitions(&mut reader, &ParseOptions::default())?;
+
+        let entries = self.entries();
+
+        if format != self.format || file_definitions.len() != entries.len() {
+            return Ok(false);
+        }
+
+        let fold = self.case_insensitive || self.filename_case != FilenameCase::Preserve;
+
+        let tables_match = entries.iter().zip(&file_definitions).all(|(&(filepath, file_data), file_definition)| {
+            let filepaths_match = if fold {
+                path::fold_case(&path::normalize(filepath)) == path::fold_case(&file_definition.filepath)
+            } else {
+                path::normalize(filepath) == file_definition.filepath
+            };
+
+            filepaths_match && file_data.len() as u64 == file_definition.size
+        });
+
+        if !tables_match || !compare_data {
+            return Ok(tables_match);
+        }
+
+        for (&(_, file_data), file_definition) in entries.iter().zip(&file_definitions) {
+            if file_definition.read_data(&mut reader)? != *file_data {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Creates a new archive object without files, keeping the settings of the
+    /// archive.
+    fn emptied(&self) -> Gob {
+        Gob {
+            files: GobMap::new(),
+            metadata: HashMap::new(),
+            raw_layout: None,
+            ..*self
+        }
+    }
+
+    /// Finds the position within [`Gob::files`] of the file at a given path,
+    /// preferring an exact match over one ignoring case.
+    fn position(&self, path: &Path) -> Option<usize> {
+        self.position_with_case(path, self.case_insensitive)
+    }
+
+    /// Finds the position within [`Gob::files`] of the file at a given path,
+    /// preferring an exact match over one ignoring case if enabled.
+    fn position_with_case(&self, path: &Path, ignore_case: bool) -> Option<usize> {
+        let path = path::normalize(path);
+
+        if let Some(i) = self.files.get_index_of(&path) {
+            return Some(i);
+        }
+
+        if !ignore_case {
+            return None;
+        }
+
+        self.files
+            .keys()
+            .position(|filepath| path::eq_ignore_ascii_case(filepath, &path))
+    }
+
+    /// Merges the files of another archive into the current archive object,
+    /// resolving files present in both according to a given [`MergeStrategy`].
+    /// 
+    /// Filepaths are compared regardless of whether they are separated by slashes
+    /// or backslashes, and, if the current archive is case-insensitive (see
+    /// [`Gob::set_case_insensitive`]), regardless of their case. Overwritten files
+    /// keep their position and filepath within the current archive.
+    /// 
+    /// # Examples
+    /// 
+    /// Layers a patch archive on top of a base archive:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::{Gob, MergeStrategy};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut base = Gob::new();
+    /// 
+    ///     base.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     base.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    ///     let mut patch = Gob::new();
+    /// 
+    ///     patch.files.insert(PathBuf::from("foo.bar"), b"foobaz".to_vec());
+    /// 
+    ///     base.merge(patch, MergeStrategy::Overwrite)?;
+    /// 
+    ///     assert_eq!(base.get("foo.bar"), Some(&b"foobaz"[..]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Fails on conflicting files, leaving the current archive unchanged:
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::{Gob, MergeStrategy}, error::GobError};
+    /// 
+    /// let mut base = Gob::new();
+    /// 
+    /// base.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    /// let mut patch = Gob::new();
+    /// 
+    /// patch.files.insert(PathBuf::from("foo.bar"), b"foobaz".to_vec());
+    /// 
+    /// patch.files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+    /// 
+    /// let result = base.merge(patch, MergeStrategy::Error);
+    /// 
+    /// assert!(matches!(result, Err(GobError::MergeConflict(filepaths)) if filepaths == [PathBuf::from("foo.bar")]));
+    /// 
+    /// assert_eq!(base.len(), 1);
+    /// ```
+    pub fn merge(&mut self, other: Gob, strategy: MergeStrategy) -> Result<(), GobError> {
+        let files: Vec<(PathBuf, Vec<u8>)> = other.files
+            .into_iter()
+            .map(|(filepath, data)| (path::normalize(&filepath), data))
+            .collect();
+
+        if strategy == MergeStrategy::Error {
+            let conflicts: Vec<PathBuf> = files
+                .iter()
+                .filter(|(filepath, _)| self.position(filepath).is_some())
+                .map(|(filepath, _)| filepath.clone())
+                .collect();
+
+            if !conflicts.is_empty() {
+                return Err(GobError::MergeConflict(conflicts));
+            }
+        }
+
+        for (filepath, data) in files {
+            match self.position(&filepath) {
+                Some(i) => {
+                    if strategy == MergeStrategy::Overwrite {
+                        self.files[i] = data;
+                    }
+                }
+                None => {
+                    self.files.insert(filepath, data);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Gob {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<GobMap> for Gob {
+    fn from(files: GobMap) -> Self {
+        Self {
+            files,
+            case_insensitive: false,
+            deterministic: false,
+            deduplicating: false,
+            format: GobFormat::default(),
+            version: Self::VERSION,
+            filename_encoding: FilenameEncoding::default(),
+            filename_case: FilenameCase::default(),
+            data_alignment: 1,
+            body_offset: 12,
+            padded_size: None,
+            metadata: HashMap::new(),
+            raw_layout: None,
+            #[cfg(feature = "compression")]
+            compression: CompressionCodec::None,
+        }
+    }
+}
+
+/// Creates a new [`Gob`] object from a [`BTreeMap`] of filepaths and data,
+/// keeping the files in the order of the map.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::{collections::BTreeMap, path::PathBuf};
+/// use gob_rs::core::Gob;
+/// 
+/// let mut files = BTreeMap::new();
+/// 
+/// files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+/// files.insert(PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec());
+/// 
+/// let gob = Gob::from(files.clone());
+/// 
+/// assert_eq!(gob.files.get_index(0), Some((&PathBuf::from("fizz.buzz"), &b"fizzbuzz".to_vec())));
+/// 
+/// assert_eq!(gob.into_btree(), files);
+/// ```
+impl From<BTreeMap<PathBuf, Vec<u8>>> for Gob {
+    fn from(files: BTreeMap<PathBuf, Vec<u8>>) -> Self {
+        Self::from(files.into_iter().collect::<GobMap>())
+    }
+}
+
+/// Parses a GOB archive from its data in memory, like [`Gob::from_bytes`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     assert_eq!(Gob::try_from(&data[..])?, gob);
+/// 
+///     Ok(())
+/// }
+/// ```
+impl TryFrom<&[u8]> for Gob {
+    type Error = GobError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(data)
+    }
+}
+
+/// Parses a GOB archive from its data in memory, like [`Gob::from_bytes`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+///     let data: Vec<u8> = gob.as_bytes()?;
+/// 
+///     assert_eq!(Gob::try_from(data)?, gob);
+/// 
+///     Ok(())
+/// }
+/// ```
+impl TryFrom<Vec<u8>> for Gob {
+    type Error = GobError;
 
-        Self {
-            files,
+    fn try_from(data: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_bytes(&data)
+    }
+}
+
+/// Summarizes the archive, listing the filepaths of its files along with the
+/// sizes of their data instead of the data itself.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+/// assert_eq!(format!("{gob:?}"), r#"Gob { len: 1, files: {"foo.bar": 6} }"#);
+/// ```
+impl fmt::Debug for Gob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gob")
+            .field("len", &self.files.len())
+            .field("files", &Sizes(self))
+            .finish()
+    }
+}
+
+/// The filepaths of the files of an archive along with the sizes of their data,
+/// as listed by the [`fmt::Debug`] implementation of [`Gob`].
+struct Sizes<'a>(&'a Gob);
+
+impl fmt::Debug for Sizes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.sizes()).finish()
+    }
+}
+
+/// Summarizes the archive in a single line, giving the number of files and
+/// the sum of the sizes of their data.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+/// assert_eq!(gob.to_string(), "GOB archive: 1 file, 6 B");
+/// 
+/// gob.files.insert(PathBuf::from("fizz.buzz"), vec![0; 1258291]);
+/// 
+/// assert_eq!(gob.to_string(), "GOB archive: 2 files, 1.2 MiB");
+/// ```
+impl fmt::Display for Gob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        let files = match self.files.len() {
+            1 => "file",
+            _ => "files",
+        };
+
+        write!(f, "GOB archive: {} {files}, ", self.files.len())?;
+
+        let total_size = self.total_size();
+
+        if total_size < 1024 {
+            return write!(f, "{total_size} B");
+        }
+
+        let mut size = total_size as f64 / 1024.0;
+
+        let mut unit = 0;
+
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+
+            unit += 1;
         }
+
+        write!(f, "{size:.1} {}", UNITS[unit])
     }
 }
 
-impl From<GobMap> for Gob {
-    fn from(files: GobMap) -> Self {
-        Self {
-            files
+/// Compares the files of two [`Gob`] objects, regardless of their order and
+/// the settings of the archives.
+/// 
+/// # Examples
+/// 
+/// Checks that archives survive a round trip through their binary
+/// representation:
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::{core::Gob, format::GobFormat};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let fixtures = [
+///         vec![],
+///         vec![(PathBuf::from("a"), vec![0])],
+///         vec![(PathBuf::from("a".repeat(127)), b"foobar".to_vec())],
+///         vec![
+///             (PathBuf::from("mission/foo.bar"), b"foobar".to_vec()),
+///             (PathBuf::from("mission/fizz.buzz"), vec![]),
+///         ],
+///     ];
+/// 
+///     for files in fixtures {
+///         let gob: Gob = files.into_iter().collect();
+/// 
+///         assert!(Gob::from_bytes(&gob.as_bytes()?)? == gob);
+///     }
+/// 
+///     let mut gob = Gob::new();
+/// 
+///     gob.set_format(GobFormat::DarkForces);
+/// 
+///     gob.files.insert(PathBuf::from("FOO.BAR"), b"foobar".to_vec());
+/// 
+///     assert!(Gob::from_bytes(&gob.as_bytes()?)? == gob);
+/// 
+///     Ok(())
+/// }
+/// ```
+impl PartialEq for Gob {
+    fn eq(&self, other: &Self) -> bool {
+        self.files == other.files
+    }
+}
+
+impl Eq for Gob {}
+
+/// Creates a new [`Gob`] object from an iterator of filepaths and data.
+/// 
+/// # Examples
+/// 
+/// Filters the files of an archive:
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(
+///     PathBuf::from("foo.bar"),
+///     b"foobar".to_vec(),
+/// );
+/// 
+/// gob.files.insert(
+///     PathBuf::from("fizz.buzz"),
+///     b"fizzbuzz".to_vec(),
+/// );
+/// 
+/// let gob: Gob = gob
+///     .into_iter()
+///     .filter(|(filepath, _)| filepath.extension() == Some("bar".as_ref()))
+///     .collect();
+/// 
+/// assert_eq!(gob.files.len(), 1);
+/// ```
+impl FromIterator<(PathBuf, Vec<u8>)> for Gob {
+    fn from_iter<I: IntoIterator<Item = (PathBuf, Vec<u8>)>>(iter: I) -> Self {
+        Self::from(GobMap::from_iter(iter))
+    }
+}
+
+/// Inserts files from an iterator of filepaths and data into the archive, like
+/// inserting them into [`Gob::files`], replacing the data of files already at
+/// the same filepaths.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+/// 
+/// gob.extend([
+///     (PathBuf::from("foo.bar"), b"foobaz".to_vec()),
+///     (PathBuf::from("fizz.buzz"), b"fizzbuzz".to_vec()),
+/// ]);
+/// 
+/// assert_eq!(gob.len(), 2);
+/// 
+/// assert_eq!(gob.get("foo.bar"), Some(&b"foobaz"[..]));
+/// ```
+impl Extend<(PathBuf, Vec<u8>)> for Gob {
+    fn extend<I: IntoIterator<Item = (PathBuf, Vec<u8>)>>(&mut self, iter: I) {
+        self.files.extend(iter);
+    }
+}
+
+impl IntoIterator for Gob {
+    type Item = (PathBuf, Vec<u8>);
+
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self.files.into_iter())
+    }
+}
+
+/// Iterates over the files of the archive by reference.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(
+///     PathBuf::from("foo.bar"),
+///     b"foobar".to_vec(),
+/// );
+/// 
+/// for (filepath, data) in &gob {
+///     println!("path: {} data: {:?}", filepath.display(), data);
+/// }
+/// ```
+impl<'a> IntoIterator for &'a Gob {
+    type Item = (&'a PathBuf, &'a Vec<u8>);
+
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter(self.files.iter())
+    }
+}
+
+/// An iterator over the files of an archive, in insertion order, created by
+/// the [`IntoIterator`] implementation of [`Gob`].
+#[derive(Debug)]
+pub struct IntoIter(indexmap::map::IntoIter<PathBuf, Vec<u8>>);
+
+impl Iterator for IntoIter {
+    type Item = (PathBuf, Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IntoIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+/// An iterator over the files of an archive by reference, in insertion
+/// order, created by [`Gob::iter`] and the [`IntoIterator`] implementation of
+/// `&Gob`.
+#[derive(Clone, Debug)]
+pub struct Iter<'a>(indexmap::map::Iter<'a, PathBuf, Vec<u8>>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a PathBuf, &'a Vec<u8>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+/// A strategy for resolving files present in both archives when merging archives.
+/// See [`Gob::merge`].
+/// 
+/// Defaults to [`MergeStrategy::Error`], so that no data is dropped unless
+/// asked for.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use gob_rs::core::MergeStrategy;
+/// 
+/// assert_eq!(MergeStrategy::default(), MergeStrategy::Error);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Replace the data of the file in the current archive.
+    Overwrite,
+    /// Keep the data of the file in the current archive.
+    KeepExisting,
+    /// Fail with [`GobError::MergeConflict`], without merging any files.
+    #[default]
+    Error,
+}
+
+/// A definition of a file within a GOB archive, describing where the data of
+/// the file is located.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::{io::Cursor, path::PathBuf};
+/// use gob_rs::{core::Gob, index::GobIndex};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(
+///         PathBuf::from("foo.bar"),
+///         b"foobar".to_vec(),
+///     );
+/// 
+///     let index = GobIndex::new(Cursor::new(gob.as_bytes()?))?;
+/// 
+///     let file_definition = &index.entries()[0];
+/// 
+///     assert_eq!(file_definition.offset, 16 + 136);
+/// 
+///     assert_eq!(file_definition.size, 6);
+/// 
+///     assert_eq!(file_definition.filepath, PathBuf::from("foo.bar"));
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDefinition {
+    /// The byte address where the data of the file starts.
+    pub offset: u64,
+    /// The size of the data of the file in bytes.
+    pub size: u64,
+    /// The path of the file within the archive.
+    pub filepath: PathBuf,
+}
+
+impl FileDefinition {
+    pub(crate) fn read_data<R: Read + Seek>(&self, reader: &mut R) -> Result<Vec<u8>, GobError> {
+        reader.seek(SeekFrom::Start(self.offset))?;
+
+        let mut data: Vec<u8> = vec![0; self.size as usize];
+
+        byte::read_exact(reader, &mut data, "data of file")?;
+
+        Ok(data)
+    }
+}
+
+/// The layout of a parsed GOB archive, allowing it to be written again byte
+/// for byte. See [`Gob::raw_layout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawLayout {
+    format: GobFormat,
+    length: u64,
+    /// The file definitions whose data is kept by the archive object.
+    definitions: Vec<FileDefinition>,
+    /// The bytes not covered by the data of the kept files, along with their
+    /// offsets, including the header and the file definitions.
+    filler: Vec<(u64, Vec<u8>)>,
+}
+
+impl RawLayout {
+    /// Captures the layout of an archive from a given reader, given the file
+    /// definitions whose data is kept.
+    fn capture<R: Read + Seek>(reader: &mut R, format: GobFormat, definitions: Vec<FileDefinition>) -> Result<Self, GobError> {
+        let length = reader.seek(SeekFrom::End(0))?;
+
+        let regions: Vec<Region> = definitions
+            .iter()
+            .map(|file_definition| (file_definition.offset, file_definition.offset + file_definition.size))
+            .collect();
+
+        let mut filler: Vec<(u64, Vec<u8>)> = Vec::new();
+
+        for gap in Gob::gaps(regions, length) {
+            let mut bytes = vec![0; gap.size as usize];
+
+            reader.seek(SeekFrom::Start(gap.offset))?;
+
+            byte::read_exact(reader, &mut bytes, "layout")?;
+
+            filler.push((gap.offset, bytes));
+        }
+
+        Ok(Self {
+            format,
+            length,
+            definitions,
+            filler,
+        })
+    }
+
+    /// Gets the length in bytes of the archive as parsed.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Checks whether the archive as parsed was empty, which for a valid
+    /// archive is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+/// What writing a GOB archive would produce, as computed by
+/// [`Gob::check_packable`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackPlan {
+    /// The size of the binary representation of the archive in bytes.
+    pub size: u64,
+    /// The filepath of each file together with the offset and the size of its
+    /// data, in the order the files are written in.
+    pub entries: Vec<(PathBuf, u64, u64)>,
+}
+
+/// The progress of reading, writing or extracting the files of a GOB archive, as
+/// reported by [`Gob::from_file_with_progress`], [`Gob::to_file_with_progress`]
+/// and [`Gob::extract_all_with_progress`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// The amount of files processed so far.
+    pub files_done: usize,
+    /// The amount of files to process.
+    pub files_total: usize,
+    /// The summed sizes of the files processed so far.
+    pub bytes_done: u64,
+    /// The summed sizes of the files to process.
+    pub bytes_total: u64,
+}
+
+/// Details about the layout of a GOB archive, as determined by
+/// [`Gob::parse_details`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDetails {
+    /// The variant of the GOB format the archive is written in.
+    pub format: GobFormat,
+    /// The length of the archive in bytes.
+    pub length: u64,
+    /// The offset following the last byte referenced by the header, the file
+    /// definitions or the data of any file.
+    pub end: u64,
+    /// Whether the archive contains bytes past [`ParseDetails::end`].
+    pub has_trailing_data: bool,
+}
+
+/// Observations made while parsing a GOB archive, as reported by
+/// [`Gob::from_reader_verbose`] and [`Gob::from_file_verbose`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseReport {
+    /// The variant of the GOB format the archive is written in.
+    pub format: GobFormat,
+    /// The version in the header of the archive.
+    pub version: u32,
+    /// The amount of file definitions, including those of duplicate filepaths.
+    pub file_count: usize,
+    /// Whether any filepath contains a backslash, i.e. consists of multiple
+    /// components.
+    pub has_directories: bool,
+    /// The amount of file definitions whose filepath occurs in a previous file
+    /// definition.
+    pub duplicate_count: usize,
+    /// The amount of bytes not referenced by the header, the file definitions
+    /// or the data of any file, as determined by [`Gob::layout_report`].
+    pub gap_size: u64,
+    /// Whether the archive contains bytes past the last byte referenced by the
+    /// header, the file definitions or the data of any file, as determined by
+    /// [`Gob::parse_details`].
+    pub has_trailing_data: bool,
+}
+
+/// Something making a well-formed GOB archive non-canonical, i.e. different
+/// from how [`Gob::as_bytes`] writes archives deterministically (see
+/// [`Gob::set_deterministic`]). See [`Gob::from_reader_with_warnings`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseWarning {
+    /// Bytes between the header, the file definitions and the data of the files
+    /// are not referenced by any of them.
+    Gap {
+        /// The offset of the first unreferenced byte.
+        offset: u64,
+        /// The amount of unreferenced bytes.
+        size: u64,
+    },
+    /// Bytes follow the last byte referenced by the header, the file
+    /// definitions or the data of any file.
+    TrailingData {
+        /// The amount of trailing bytes.
+        size: u64,
+    },
+    /// The file definitions are not sorted by their filepaths.
+    UnsortedTable,
+    /// A filepath contains slashes in addition to the backslashes separating
+    /// the components of filepaths within GOB archives.
+    MixedSeparators(PathBuf),
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gap { offset, size } => write!(f, "{size} unreferenced bytes at offset {offset}"),
+            Self::TrailingData { size } => write!(f, "{size} trailing bytes"),
+            Self::UnsortedTable => write!(f, "file definitions are not sorted by filepath"),
+            Self::MixedSeparators(filepath) => write!(f, "filepath contains slashes: {}", filepath.display()),
         }
     }
 }
 
+/// A report on how the space of a GOB archive is used, as determined by
+/// [`Gob::layout_report`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayoutReport {
+    /// The length of the archive in bytes.
+    pub total_size: u64,
+    /// The summed sizes of the files, counting data shared by multiple file
+    /// definitions once for each.
+    pub data_size: u64,
+    /// The amount of bytes occupied by the header and the file definitions.
+    pub overhead: u64,
+    /// The amount of bytes not referenced by the header, the file definitions
+    /// or the data of any file.
+    pub gap_size: u64,
+    /// The largest range of unreferenced bytes, if any, preferring the first one
+    /// of equal size.
+    pub largest_gap: Option<Gap>,
+}
+
+/// A range of bytes within a GOB archive not referenced by the header, the file
+/// definitions or the data of any file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gap {
+    /// The offset of the first byte of the range.
+    pub offset: u64,
+    /// The length of the range in bytes.
+    pub size: u64,
+}
+
+/// A range of bytes within a GOB archive referenced by the data of two files,
+/// as found by [`Gob::overlaps`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    /// The offset of the first byte of the range.
+    pub offset: u64,
+    /// The length of the range in bytes.
+    pub size: u64,
+    /// The filepaths of the files, ordered by the offsets of their data.
+    pub filepaths: [PathBuf; 2],
+}
 
-struct FileDefinition {
-    offset: usize,
-    size: usize,
-    filepath: PathBuf,
+/// The metadata of the source file of a file within a GOB archive, captured
+/// when packing a directory. See [`Gob::metadata`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntryMeta {
+    /// The time the source file was last modified, if the platform reports it.
+    pub modified: Option<SystemTime>,
+    /// The size of the source file in bytes.
+    pub size: u64,
 }
 
-/// A [`HashMap`] keyed by [`PathBuf`] containing [`Vec`] of [`u8`] (bytes),
+/// An [`IndexMap`] keyed by [`PathBuf`] containing [`Vec`] of [`u8`] (bytes),
 /// representing the structure of a GOB archive.
 /// 
+/// The map preserves the order in which files are inserted, which is also the
+/// order in which they are written to and read from a GOB archive.
+/// 
 /// # Examples
 /// 
 /// Creating object and inserting file:
@@ -380,4 +6871,30 @@ struct FileDefinition {
 ///     Some(&b"fizzbuzz".to_vec()),
 /// );
 /// ```
-pub type GobMap = HashMap<PathBuf, Vec<u8>>;
\ No newline at end of file
+/// 
+/// Preserving the order of files in a GOB archive:
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     for filepath in ["c.bar", "a.bar", "b.bar"] {
+///         gob.files.insert(PathBuf::from(filepath), b"foobar".to_vec());
+///     }
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     let gob = Gob::from_reader(&mut std::io::Cursor::new(&data))?;
+/// 
+///     let filepaths: Vec<&PathBuf> = gob.files.keys().collect();
+/// 
+///     assert_eq!(filepaths, ["c.bar", "a.bar", "b.bar"]);
+/// 
+///     assert_eq!(gob.as_bytes()?, data);
+/// 
+///     Ok(())
+/// }
+/// ```
+pub type GobMap = IndexMap<PathBuf, Vec<u8>>;
\ No newline at end of file
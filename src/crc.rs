@@ -0,0 +1,33 @@
+/// The lookup table of the CRC-32 (IEEE 802.3) checksum, generated at compile
+/// time from the reversed polynomial `0xEDB88320`.
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+
+    while i < 256 {
+        let mut crc = i as u32;
+
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+
+            bit += 1;
+        }
+
+        table[i] = crc;
+
+        i += 1;
+    }
+
+    table
+};
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of given data, as used by zip
+/// and PNG.
+pub fn crc32(data: &[u8]) -> u32 {
+    !data
+        .iter()
+        .fold(!0u32, |crc, &byte| (crc >> 8) ^ TABLE[((crc ^ byte as u32) & 0xFF) as usize])
+}
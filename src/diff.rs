@@ -0,0 +1,32 @@
+//! Diff module of the library
+
+use std::path::PathBuf;
+
+/// The differences between two GOB archives. See [`Gob::diff`](crate::core::Gob::diff).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GobDiff<'a> {
+    /// The filepaths of files only present in the other archive.
+    pub added: Vec<&'a PathBuf>,
+    /// The filepaths of files only present in the current archive.
+    pub removed: Vec<&'a PathBuf>,
+    /// The files present in both archives, but with different data.
+    pub modified: Vec<Modified<'a>>,
+}
+
+/// A file present in both of two compared GOB archives, but with different
+/// data. See [`GobDiff::modified`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Modified<'a> {
+    /// The filepath of the file.
+    pub path: &'a PathBuf,
+    /// The change in size of the data of the file in bytes; negative if the
+    /// file shrank.
+    pub size_delta: i64,
+}
+
+impl GobDiff<'_> {
+    /// Checks whether the archives contain the same files with the same data.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
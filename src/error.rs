@@ -0,0 +1,173 @@
+//! Error module of the library
+
+use std::{
+    error, fmt, io, path::PathBuf
+};
+
+use crate::core::ParseWarning;
+
+/// An error which can occur while parsing or constructing a GOB archive.
+/// 
+/// # Examples
+/// 
+/// Matches on the reason a GOB archive could not be parsed:
+/// 
+/// ```
+/// use std::io::Cursor;
+/// use gob_rs::{core::Gob, error::GobError};
+/// 
+/// let mut reader = Cursor::new(b"FOO \x14\x00\x00\x00\x0c\x00\x00\x00\x00\x00\x00\x00".to_vec());
+/// 
+/// assert!(matches!(Gob::from_reader(&mut reader), Err(GobError::BadSignature)));
+/// ```
+/// 
+/// Reports which field of a truncated archive could not be read:
+/// 
+/// ```
+/// use gob_rs::{core::Gob, error::GobError};
+/// 
+/// let error = Gob::from_bytes(b"GOB \x14\x00\x00\x00\x0c").unwrap_err();
+/// 
+/// assert!(matches!(error, GobError::Truncated { field: "body offset" }));
+/// 
+/// assert_eq!(error.to_string(), "GOB file ends while reading body offset.");
+/// ```
+#[derive(Debug)]
+pub enum GobError {
+    /// The signature in the header is not the one of a GOB archive.
+    BadSignature,
+    /// The version in the header is not supported, carrying the version read.
+    BadVersion(u32),
+    /// A filepath, including its null terminator, does not fit into the filepath
+    /// field of a file definition.
+    FilepathTooLong {
+        /// The filepath.
+        path: PathBuf,
+        /// The length of the encoded filepath in bytes.
+        len: usize,
+        /// The maximum length of a filepath in bytes, leaving room for the null
+        /// terminator.
+        max: usize,
+    },
+    /// A filepath contains a null byte, which would end it early within the
+    /// filepath field of a file definition.
+    NullInPath(PathBuf),
+    /// A filepath is absolute or contains `..` components, so it would escape
+    /// the directory the archive is extracted into.
+    UnsafePath(PathBuf),
+    /// A filepath in a file definition is not valid UTF-8.
+    InvalidUtf8Filepath(Vec<u8>),
+    /// A filepath contains characters which are unrepresentable in the encoding
+    /// of the archive.
+    UnrepresentableFilepath(PathBuf),
+    /// The archive does not contain a file at a filepath.
+    FileNotFound(PathBuf),
+    /// The data of a file extends beyond the end of the archive.
+    FileOutOfBounds(PathBuf),
+    /// The offset or the size of a file within the archive does not fit into the
+    /// 32-bit fields of a file definition.
+    SizeOverflow {
+        /// The filepath of the file.
+        path: PathBuf,
+        /// The offset or the size which does not fit.
+        size: u64,
+    },
+    /// A file does not fit into a volume of the maximum size on its own.
+    FileTooLarge {
+        /// The filepath of the file.
+        path: PathBuf,
+        /// The size of a volume containing only the file.
+        size: u64,
+    },
+    /// The archive is larger than the size it is to be padded to.
+    ExceedsPaddedSize {
+        /// The size of the archive without padding.
+        size: u64,
+        /// The size the archive is to be padded to.
+        padded_size: u64,
+    },
+    /// A filepath contains a component which is illegal as a filename on the
+    /// current platform, so the file cannot be extracted.
+    IllegalPathComponent {
+        /// The filepath of the file.
+        path: PathBuf,
+    },
+    /// The archive ends before a field could be read completely.
+    Truncated {
+        /// A description of the field being read.
+        field: &'static str,
+    },
+    /// A filepath occurs more than once within the archive.
+    DuplicateFilepath(PathBuf),
+    /// The archive is well-formed, but not canonical, and is parsed in strict
+    /// mode (see [`ParseOptions::mode`](crate::options::ParseOptions::mode)).
+    NonCanonical(ParseWarning),
+    /// A directory required to contain files contains none.
+    EmptySource(PathBuf),
+    /// Filepaths are present in both archives being merged.
+    MergeConflict(Vec<PathBuf>),
+    /// A [`GobWriter`](crate::writer::GobWriter) failed while writing the data
+    /// of a file, so the archive it writes is corrupt.
+    Poisoned,
+    /// An underlying I/O operation failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for GobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadSignature => write!(f, "Bad signature in header of GOB file."),
+            Self::BadVersion(version) => write!(f, "Bad version {version:#x} in header of GOB file."),
+            Self::FilepathTooLong { path, len, max } => write!(f, "Filepath of {len} bytes exceeds limit of {max} bytes of filepath field of GOB file: {}", path.display()),
+            Self::NullInPath(filepath) => write!(f, "Filepath contains null byte: {}", filepath.display()),
+            Self::UnsafePath(filepath) => write!(f, "Filepath is absolute or contains parent components: {}", filepath.display()),
+            Self::InvalidUtf8Filepath(bytes) => write!(f, "Cannot convert following bytes to string: {bytes:?}"),
+            Self::UnrepresentableFilepath(filepath) => write!(f, "Filepath contains characters unrepresentable in encoding: {}", filepath.display()),
+            Self::FileNotFound(filepath) => write!(f, "No file in GOB archive at filepath: {}", filepath.display()),
+            Self::FileOutOfBounds(filepath) => write!(f, "Data of file extends beyond end of GOB file: {}", filepath.display()),
+            Self::SizeOverflow { path, size } => write!(f, "Offset or size {size} of file exceeds 32 bits: {}", path.display()),
+            Self::FileTooLarge { path, size } => write!(f, "Volume of size {size} containing only file exceeds maximum size: {}", path.display()),
+            Self::ExceedsPaddedSize { size, padded_size } => write!(f, "Size {size} of GOB file exceeds size {padded_size} to pad to."),
+            Self::IllegalPathComponent { path } => write!(f, "Filepath contains component illegal on this platform: {}", path.display()),
+            Self::Truncated { field } => write!(f, "GOB file ends while reading {field}."),
+            Self::DuplicateFilepath(filepath) => write!(f, "Filepath occurs more than once: {}", filepath.display()),
+            Self::NonCanonical(warning) => write!(f, "GOB file is not canonical: {warning}"),
+            Self::EmptySource(path) => write!(f, "No files found in directory: {}", path.display()),
+            Self::MergeConflict(filepaths) => {
+                write!(f, "Filepaths are present in both archives:")?;
+
+                for filepath in filepaths {
+                    write!(f, " {}", filepath.display())?;
+                }
+
+                Ok(())
+            }
+            Self::Poisoned => write!(f, "GOB writer failed earlier while writing data of file."),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl error::Error for GobError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for GobError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<GobError> for io::Error {
+    fn from(error: GobError) -> Self {
+        match error {
+            GobError::Io(error) => error,
+            error => io::Error::new(io::ErrorKind::InvalidData, error),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Format module of the library
+
+/// A variant of the GOB format.
+/// 
+/// # Examples
+/// 
+/// Generates a GOB file of the Dark Forces variant:
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::{core::Gob, format::GobFormat};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.set_format(GobFormat::DarkForces);
+/// 
+///     gob.files.insert(
+///         PathBuf::from("FOO.BAR"),
+///         b"foobar".to_vec(),
+///     );
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     assert_eq!(&data[..4], b"GOB\x0a");
+/// 
+///     let gob = Gob::from_reader(&mut std::io::Cursor::new(data))?;
+/// 
+///     assert_eq!(gob.format(), GobFormat::DarkForces);
+/// 
+///     assert_eq!(gob.get("FOO.BAR"), Some(&b"foobar"[..]));
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GobFormat {
+    /// The variant used by Star Wars: Dark Forces.
+    /// 
+    /// The header consists of the signature `"GOB\x0a"` and the offset of the
+    /// file definitions, which follow the data of the files. Filepaths may
+    /// be at most 12 bytes long, leaving room for the null terminator.
+    DarkForces,
+    /// The variant used by games built on the Sith engine, e.g. Star Wars Jedi
+    /// Knight: Dark Forces II and Indiana Jones and the Infernal Machine.
+    /// 
+    /// The header consists of the signature `"GOB "`, the version `0x14` and
+    /// the offset of the file definitions, which precede the data of the
+    /// files. Filepaths may be at most 127 bytes long, leaving room for the
+    /// null terminator.
+    #[default]
+    JediKnight,
+}
+
+impl GobFormat {
+    /// Detects the variant from the signature in the header of a GOB file.
+    pub(crate) fn from_signature(signature: &[u8; 4]) -> Option<Self> {
+        match signature {
+            b"GOB\x0a" => Some(Self::DarkForces),
+            b"GOB " => Some(Self::JediKnight),
+            _ => None,
+        }
+    }
+
+    /// Gets the signature in the header of a GOB file of the variant.
+    pub(crate) fn signature(self) -> &'static [u8; 4] {
+        match self {
+            Self::DarkForces => b"GOB\x0a",
+            Self::JediKnight => b"GOB ",
+        }
+    }
+
+    /// Gets the size of the filepath field of a file definition in bytes,
+    /// including the null terminator. Used by both parsing and writing.
+    pub(crate) fn filepath_len(self) -> usize {
+        match self {
+            Self::DarkForces => 13,
+            Self::JediKnight => 128,
+        }
+    }
+}
+
+/// An encoding of the filepaths within a GOB archive.
+/// 
+/// # Examples
+/// 
+/// Parses a GOB file whose filepaths are encoded as CP437:
+/// 
+/// ```
+/// use std::{io::Cursor, path::PathBuf};
+/// use gob_rs::{core::Gob, format::FilenameEncoding, options::ParseOptions};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.set_filename_encoding(FilenameEncoding::Cp437);
+/// 
+///     gob.files.insert(
+///         PathBuf::from("CAFÉ.BM"),
+///         b"foobar".to_vec(),
+///     );
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     assert_eq!(&data[24..31], b"CAF\x90.BM");
+/// 
+///     let options = ParseOptions::new().filename_encoding(FilenameEncoding::Cp437);
+/// 
+///     let gob = Gob::from_reader_with_options(&mut Cursor::new(data), options)?;
+/// 
+///     assert_eq!(gob.get("CAFÉ.BM"), Some(&b"foobar"[..]));
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FilenameEncoding {
+    /// UTF-8, which is a superset of ASCII.
+    #[default]
+    Utf8,
+    /// Code page 437, the character set of the original IBM PC.
+    Cp437,
+    /// ISO 8859-1, mapping each byte to the Unicode code point of the same value.
+    Latin1,
+}
+
+/// The ASCII case filepaths within a GOB archive are written in.
+/// 
+/// Only the written bytes are affected, not the filepaths within
+/// [`Gob::files`](crate::core::Gob::files).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FilenameCase {
+    /// Filepaths are written as they are.
+    #[default]
+    Preserve,
+    /// Filepaths are written in uppercase, like by the original LucasArts tools.
+    Uppercase,
+    /// Filepaths are written in lowercase.
+    Lowercase,
+}
+
+impl FilenameCase {
+    /// Converts the ASCII case of the encoded bytes of a filepath.
+    pub(crate) fn apply(self, mut bytes: Vec<u8>) -> Vec<u8> {
+        match self {
+            Self::Preserve => {}
+            Self::Uppercase => bytes.make_ascii_uppercase(),
+            Self::Lowercase => bytes.make_ascii_lowercase(),
+        }
+
+        bytes
+    }
+}
+
+/// The characters of code page 437 from `0x80` to `0xFF`.
+const CP437: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å',
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ',
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»',
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐',
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧',
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀',
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩',
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+impl FilenameEncoding {
+    /// Decodes the bytes of a filepath, returning `None` if they are invalid.
+    pub(crate) fn decode(self, bytes: &[u8]) -> Option<String> {
+        match self {
+            Self::Utf8 => crate::byte::string_from_bytes(bytes).ok(),
+            Self::Cp437 => Some(
+                bytes
+                    .iter()
+                    .map(|&byte| match byte {
+                        0x00..=0x7f => byte as char,
+                        _ => CP437[byte as usize - 0x80],
+                    })
+                    .collect()
+            ),
+            Self::Latin1 => Some(bytes.iter().map(|&byte| byte as char).collect()),
+        }
+    }
+
+    /// Encodes a filepath, returning `None` if it contains characters which are
+    /// unrepresentable in the encoding.
+    pub(crate) fn encode(self, string: &str) -> Option<Vec<u8>> {
+        match self {
+            Self::Utf8 => Some(string.as_bytes().to_vec()),
+            Self::Cp437 => string
+                .chars()
+                .map(|character| match character {
+                    '\0'..='\u{7f}' => Some(character as u8),
+                    _ => CP437
+                        .iter()
+                        .position(|&n| n == character)
+                        .map(|i| (i + 0x80) as u8),
+                })
+                .collect(),
+            Self::Latin1 => string
+                .chars()
+                .map(|character| u8::try_from(character).ok())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Index module of the library
+
+use std::{
+    fs, io::{Error, ErrorKind, Read, Seek}, path::{Path, PathBuf}
+};
+
+use crate::{core::{FileDefinition, Gob, ParsedTable}, error::GobError, format::GobFormat, options::{DuplicatePolicy, ParseOptions}, path};
+
+/// An index of a GOB archive, holding the parsed file definitions of the archive
+/// while deferring reading the data of the files until requested.
+/// 
+/// # Examples
+/// 
+/// Lists the files of a GOB archive file and reads a single one:
+/// 
+/// ```no_run
+/// use std::path::Path;
+/// use gob_rs::index::GobIndex;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut index = GobIndex::from_file(Path::new("/path/to/gob.GOB"))?;
+/// 
+///     for file_definition in index.entries() {
+///         println!("path: {} size: {}", file_definition.filepath.display(), file_definition.size);
+///     }
+/// 
+///     let data = index.read(Path::new("foo.bar"))?;
+/// 
+///     Ok(())
+/// }
+/// ```
+pub struct GobIndex<R> {
+    reader: R,
+    format: GobFormat,
+    duplicates: DuplicatePolicy,
+    entries: Vec<FileDefinition>,
+}
+
+impl GobIndex<fs::File> {
+    /// Creates a new [`GobIndex`] object from a given [`Path`] to a GOB archive file.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::index::GobIndex;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let index = GobIndex::from_file(Path::new("/path/to/gob.GOB"))?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, GobError> {
+        if !path.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        Self::new(fs::File::open(path)?)
+    }
+}
+
+impl<R: Read + Seek> GobIndex<R> {
+    /// Creates a new [`GobIndex`] object from a given reader providing the data
+    /// of a GOB archive, parsing only the header and the file definitions.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let index = GobIndex::new(Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     assert_eq!(index.entries().len(), 1);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Result<Self, GobError> {
+        Self::with_options(reader, ParseOptions::default())
+    }
+
+    /// Creates a new [`GobIndex`] object from a given reader providing the data
+    /// of a GOB archive, parsing the header and the file definitions according
+    /// to given [`ParseOptions`].
+    pub fn with_options(mut reader: R, options: ParseOptions) -> Result<Self, GobError> {
+        let ParsedTable {
+            format,
+            file_definitions: entries,
+            ..
+        } = Gob::read_file_definitions(&mut reader, &options)?;
+
+        Ok(Self {
+            reader,
+            format,
+            duplicates: options.duplicates,
+            entries,
+        })
+    }
+
+    /// Gets the variant of the GOB format the archive is written in.
+    pub fn format(&self) -> GobFormat {
+        self.format
+    }
+
+    /// Gets the file definitions of the archive, in the order they are stored in.
+    pub fn entries(&self) -> &[FileDefinition] {
+        &self.entries
+    }
+
+    /// Iterates over the file definitions of the archive in the order their data
+    /// is physically located in, i.e. sorted by offset.
+    /// 
+    /// File definitions sharing the same offset keep the order they are stored in.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Swap the first two file definitions.
+    ///     let (first, second) = data[16..].split_at_mut(136);
+    /// 
+    ///     first.swap_with_slice(&mut second[..136]);
+    /// 
+    ///     let index = GobIndex::new(Cursor::new(data))?;
+    /// 
+    ///     assert_eq!(index.entries()[0].filepath, PathBuf::from("fizz.buzz"));
+    /// 
+    ///     let filepaths: Vec<&PathBuf> = index
+    ///         .entries_by_offset()
+    ///         .map(|file_definition| &file_definition.filepath)
+    ///         .collect();
+    /// 
+    ///     assert_eq!(filepaths, ["foo.bar", "fizz.buzz"]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn entries_by_offset(&self) -> impl Iterator<Item = &FileDefinition> {
+        let mut entries: Vec<&FileDefinition> = self.entries.iter().collect();
+
+        entries.sort_by_key(|file_definition| file_definition.offset);
+
+        entries.into_iter()
+    }
+
+    /// Reads the data of the file at a given path within the archive.
+    /// 
+    /// The path may be separated by slashes or backslashes. Returns `None` if
+    /// the archive does not contain the file. If multiple files share the path,
+    /// the one defined first is read given [`DuplicatePolicy::KeepFirst`], and
+    /// the one defined last otherwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::{Path, PathBuf}};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut index = GobIndex::new(Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     assert_eq!(index.read(Path::new("foo.bar"))?, Some(b"foobar".to_vec()));
+    /// 
+    ///     assert_eq!(index.read(Path::new("fizz.buzz"))?, None);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn read(&mut self, path: &Path) -> Result<Option<Vec<u8>>, GobError> {
+        let path = path::normalize(path);
+
+        let mut file_definitions = self.entries
+            .iter()
+            .filter(|file_definition| file_definition.filepath == path);
+
+        let file_definition = match self.duplicates {
+            DuplicatePolicy::KeepFirst => file_definitions.next(),
+            _ => file_definitions.next_back(),
+        };
+
+        match file_definition {
+            Some(file_definition) => Ok(Some(file_definition.read_data(&mut self.reader)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the data of the file defined by the file definition at a given index
+    /// within [`GobIndex::entries`].
+    /// 
+    /// Returns `None` if the index is out of range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut index = GobIndex::new(Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     assert_eq!(index.read_by_index(0)?, Some(b"foobar".to_vec()));
+    /// 
+    ///     assert_eq!(index.read_by_index(1)?, None);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn read_by_index(&mut self, i: usize) -> Result<Option<Vec<u8>>, GobError> {
+        match self.entries.get(i) {
+            Some(file_definition) => Ok(Some(file_definition.read_data(&mut self.reader)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the data of all files whose filepaths match a given glob pattern,
+    /// in the order they are stored in.
+    /// 
+    /// Patterns are matched like in [`Gob::matching`]; only the data of matching
+    /// files is read.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, index::GobIndex};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let mut index = GobIndex::new(Cursor::new(gob.as_bytes()?))?;
+    /// 
+    ///     let files = index.extract_matching("*.BAR")?;
+    /// 
+    ///     assert_eq!(files, [(PathBuf::from("foo.bar"), b"foobar".to_vec())]);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extract_matching(&mut self, pattern: &str) -> Result<Vec<(PathBuf, Vec<u8>)>, GobError> {
+        let mut files: Vec<(PathBuf, Vec<u8>)> = Vec::new();
+
+        for file_definition in &self.entries {
+            if path::matches_glob(pattern, &file_definition.filepath) {
+                files.push((file_definition.filepath.clone(), file_definition.read_data(&mut self.reader)?));
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Consumes the [`GobIndex`] object, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
@@ -3,4 +3,20 @@
 //! A Rust library for parsing and constructing archives of the LucasArts GOB format.
 
 mod byte;
+pub mod builder;
+#[cfg(feature = "compression")]
+pub mod compression;
 pub mod core;
+mod crc;
+pub mod diff;
+pub mod error;
+pub mod format;
+pub mod index;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod options;
+pub mod path;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod view;
+pub mod writer;
@@ -0,0 +1,91 @@
+//! Command-line interface for packing, unpacking and listing GOB archives.
+//! Requires the `cli` feature.
+
+use std::{
+    env, fs::File, io::BufReader, path::Path, process::ExitCode
+};
+
+use gob_rs::{core::Gob, error::GobError, index::GobIndex};
+
+const USAGE: &str = "\
+Usage:
+    gob list <archive.gob>
+    gob extract <archive.gob> <directory>
+    gob pack <directory> <archive.gob>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let result = match args.as_slice() {
+        ["list", archive] => list(Path::new(archive)),
+        ["extract", archive, directory] => extract(Path::new(archive), Path::new(directory)),
+        ["pack", directory, archive] => pack(Path::new(directory), Path::new(archive)),
+        _ => {
+            eprintln!("{USAGE}");
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints the filepath and the size of each file of an archive, followed by
+/// the totals.
+fn list(archive: &Path) -> Result<(), GobError> {
+    let index = GobIndex::from_file(archive)?;
+
+    let mut total_size = 0;
+
+    for file_definition in index.entries() {
+        println!("{:>10}  {}", human_readable(file_definition.size), file_definition.filepath.display());
+
+        total_size += file_definition.size;
+    }
+
+    println!("{:>10}  {} files", human_readable(total_size), index.entries().len());
+
+    Ok(())
+}
+
+/// Extracts the files of an archive into a directory, one file at a time.
+fn extract(archive: &Path, directory: &Path) -> Result<(), GobError> {
+    let mut reader = BufReader::new(File::open(archive)?);
+
+    Gob::extract_all(&mut reader, directory)
+}
+
+/// Packs the files of a directory into an archive.
+fn pack(directory: &Path, archive: &Path) -> Result<(), GobError> {
+    Gob::from_directory(directory)?.to_file(archive)
+}
+
+/// Formats a size in bytes using binary units, e.g. `1.5 KiB`.
+fn human_readable(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if size < 1024 {
+        return format!("{size} B");
+    }
+
+    let mut value = size as f64 / 1024.0;
+
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
@@ -0,0 +1,195 @@
+//! Mmap module of the library
+
+use std::{
+    fs, io::{Cursor, Error, ErrorKind}, path::Path
+};
+
+use memmap2::Mmap;
+
+use crate::{core::{FileDefinition, Gob, ParsedTable}, error::GobError, format::GobFormat, options::{DuplicatePolicy, ParseOptions}, path, view::GobView};
+
+/// A memory-mapped GOB archive file, holding the parsed file definitions of the
+/// archive and handing out the data of its files as slices into the mapped
+/// region without copying them.
+/// 
+/// Requires the `mmap` feature.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use gob_rs::{core::Gob, mmap::GobMmap};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(
+///         PathBuf::from("foo.bar"),
+///         b"foobar".to_vec(),
+///     );
+/// 
+///     let path = std::env::temp_dir().join("gob_rs_mmap_example.GOB");
+/// 
+///     gob.to_file(&path)?;
+/// 
+///     let mmap = GobMmap::from_file(&path)?;
+/// 
+///     assert_eq!(mmap.get("foo.bar"), Some(&b"foobar"[..]));
+/// 
+///     assert_eq!(mmap.get("fizz.buzz"), None);
+/// 
+///     drop(mmap);
+/// 
+///     std::fs::remove_file(&path)?;
+/// 
+///     Ok(())
+/// }
+/// ```
+pub struct GobMmap {
+    mmap: Mmap,
+    format: GobFormat,
+    duplicates: DuplicatePolicy,
+    entries: Vec<FileDefinition>,
+}
+
+impl GobMmap {
+    /// Creates a new [`GobMmap`] object from a given [`Path`] to a GOB archive
+    /// file, mapping it into memory and parsing the header and the file
+    /// definitions.
+    /// 
+    /// The file must not be modified by other processes while it is mapped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```no_run
+    /// use std::path::Path;
+    /// use gob_rs::mmap::GobMmap;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mmap = GobMmap::from_file(Path::new("/path/to/gob.GOB"))?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, GobError> {
+        Self::from_file_with_options(path, ParseOptions::default())
+    }
+
+    /// Creates a new [`GobMmap`] object from a given [`Path`] to a GOB archive
+    /// file, parsing the header and the file definitions according to given
+    /// [`ParseOptions`].
+    pub fn from_file_with_options(path: &Path, options: ParseOptions) -> Result<Self, GobError> {
+        if !path.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "Path is not a file.").into());
+        }
+
+        let file = fs::File::open(path)?;
+
+        // SAFETY: Modifying the file while it is mapped is documented as
+        // unsupported; bounds of all file definitions are checked on parsing.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let ParsedTable {
+            format,
+            file_definitions: entries,
+            ..
+        } = Gob::read_file_definitions(&mut Cursor::new(&mmap[..]), &options)?;
+
+        Ok(Self {
+            mmap,
+            format,
+            duplicates: options.duplicates,
+            entries,
+        })
+    }
+
+    /// Gets the variant of the GOB format the archive is written in.
+    pub fn format(&self) -> GobFormat {
+        self.format
+    }
+
+    /// Gets the file definitions of the archive, in the order they are stored in.
+    pub fn entries(&self) -> &[FileDefinition] {
+        &self.entries
+    }
+
+    /// Gets the data of the file at a given path within the archive as a slice
+    /// into the mapped region.
+    /// 
+    /// The path may be separated by slashes or backslashes. Returns `None` if
+    /// the archive does not contain the file. If multiple files share the path,
+    /// the one defined first is returned given [`DuplicatePolicy::KeepFirst`],
+    /// and the one defined last otherwise.
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        let path = path::normalize(path.as_ref());
+
+        let mut file_definitions = self.entries
+            .iter()
+            .filter(|file_definition| file_definition.filepath == path);
+
+        let file_definition = match self.duplicates {
+            DuplicatePolicy::KeepFirst => file_definitions.next(),
+            _ => file_definitions.next_back(),
+        }?;
+
+        let start = file_definition.offset as usize;
+
+        let end = start + file_definition.size as usize;
+
+        Some(&self.mmap[start..end])
+    }
+
+    /// Gets the data of the file defined by the file definition at a given index
+    /// within [`GobMmap::entries`] as a slice into the mapped region.
+    /// 
+    /// Returns `None` if the index is out of range.
+    pub fn get_by_index(&self, i: usize) -> Option<&[u8]> {
+        let file_definition = self.entries.get(i)?;
+
+        let start = file_definition.offset as usize;
+
+        let end = start + file_definition.size as usize;
+
+        Some(&self.mmap[start..end])
+    }
+
+    /// Creates a [`GobView`] of the archive, borrowing the data of its files from
+    /// the mapped region.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::{core::Gob, mmap::GobMmap};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let path = std::env::temp_dir().join("gob_rs_mmap_view_example.GOB");
+    /// 
+    ///     gob.to_file(&path)?;
+    /// 
+    ///     let mmap = GobMmap::from_file(&path)?;
+    /// 
+    ///     let view = mmap.view();
+    /// 
+    ///     assert_eq!(view.get("foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     drop(view);
+    /// 
+    ///     drop(mmap);
+    /// 
+    ///     std::fs::remove_file(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn view(&self) -> GobView<'_> {
+        GobView::from_definitions(&self.mmap, self.format, &self.entries, self.duplicates)
+    }
+}
@@ -0,0 +1,471 @@
+//! Options module of the library
+
+use std::{fmt, path::{Path, PathBuf}};
+
+#[cfg(feature = "compression")]
+use crate::compression::CompressionCodec;
+use crate::format::FilenameEncoding;
+
+/// Options controlling how GOB archives are parsed.
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use std::path::Path;
+/// use gob_rs::{core::Gob, format::FilenameEncoding, options::ParseOptions};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let options = ParseOptions::new().filename_encoding(FilenameEncoding::Latin1);
+/// 
+///     let gob = Gob::from_file_with_options(Path::new("/path/to/gob.GOB"), options)?;
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ParseOptions {
+    pub(crate) filename_encoding: FilenameEncoding,
+    pub(crate) duplicates: DuplicatePolicy,
+    pub(crate) offsets: OffsetMode,
+    pub(crate) versions: Option<fn(u32) -> bool>,
+    pub(crate) preserve_layout: bool,
+    pub(crate) mode: ParseMode,
+    #[cfg(feature = "compression")]
+    pub(crate) compression: CompressionCodec,
+}
+
+impl ParseOptions {
+    /// Creates a new [`ParseOptions`] object with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the encoding the filepaths within the archive are decoded with.
+    /// Defaults to [`FilenameEncoding::Utf8`].
+    pub fn filename_encoding(mut self, filename_encoding: FilenameEncoding) -> Self {
+        self.filename_encoding = filename_encoding;
+
+        self
+    }
+
+    /// Sets how files sharing the same filepath within the archive are handled.
+    /// Defaults to [`DuplicatePolicy::Error`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, error::GobError, options::{DuplicatePolicy, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Rename the second file to the filepath of the first one.
+    ///     data[160..169].copy_from_slice(b"foo.bar\0\0");
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::from_reader(&mut Cursor::new(&data)),
+    ///         Err(GobError::DuplicateFilepath(_)),
+    ///     ));
+    /// 
+    ///     let options = ParseOptions::new().duplicates(DuplicatePolicy::KeepLast);
+    /// 
+    ///     let gob = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+    /// 
+    ///     assert_eq!(gob.files.len(), 1);
+    /// 
+    ///     assert_eq!(gob.get("foo.bar"), Some(&b"fizzbuzz"[..]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn duplicates(mut self, duplicates: DuplicatePolicy) -> Self {
+        self.duplicates = duplicates;
+
+        self
+    }
+
+    /// Sets how the offsets of the files within the archive are interpreted.
+    /// Defaults to [`OffsetMode::Absolute`].
+    /// 
+    /// # Examples
+    /// 
+    /// Parses an archive whose offsets are relative to the data following the
+    /// file definitions:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, options::{OffsetMode, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Make the offset of the file relative.
+    ///     data[16..20].copy_from_slice(&0u32.to_le_bytes());
+    /// 
+    ///     for offsets in [OffsetMode::Relative, OffsetMode::Detect] {
+    ///         let options = ParseOptions::new().offsets(offsets);
+    /// 
+    ///         let parsed = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+    /// 
+    ///         assert_eq!(parsed, gob);
+    ///     }
+    /// 
+    ///     let options = ParseOptions::new().offsets(OffsetMode::Detect);
+    /// 
+    ///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(gob.as_bytes()?), options)?;
+    /// 
+    ///     assert_eq!(parsed, gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Files without data do not make absolute offsets look relative, even if
+    /// they are placed at offset 0:
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::Gob, options::{OffsetMode, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(PathBuf::from("foo.bar"), b"foobar".to_vec());
+    /// 
+    ///     gob.files.insert(PathBuf::from("empty.txt"), Vec::new());
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     // Place the empty file at offset 0.
+    ///     data[16 + 136..16 + 136 + 4].copy_from_slice(&0u32.to_le_bytes());
+    /// 
+    ///     let options = ParseOptions::new().offsets(OffsetMode::Detect);
+    /// 
+    ///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(&data), options)?;
+    /// 
+    ///     assert_eq!(parsed, gob);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn offsets(mut self, offsets: OffsetMode) -> Self {
+        self.offsets = offsets;
+
+        self
+    }
+
+    /// Sets which versions in the header of Jedi Knight archives are accepted,
+    /// given a predicate returning `true` for accepted versions. Defaults to
+    /// only accepting `0x14`, the version written by the games.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use gob_rs::{core::Gob, error::GobError, options::ParseOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.set_version(0x15);
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     assert!(matches!(Gob::from_bytes(&data), Err(GobError::BadVersion(0x15))));
+    /// 
+    ///     let options = ParseOptions::new().accepted_versions(|version| (0x14..=0x15).contains(&version));
+    /// 
+    ///     let parsed = Gob::from_reader_with_options(&mut Cursor::new(data), options)?;
+    /// 
+    ///     assert_eq!(parsed.version(), 0x15);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn accepted_versions(mut self, accept: fn(u32) -> bool) -> Self {
+        self.versions = Some(accept);
+
+        self
+    }
+
+    /// Checks whether a version in the header of a Jedi Knight archive is
+    /// accepted.
+    pub(crate) fn accepts_version(&self, version: u32) -> bool {
+        match self.versions {
+            Some(accept) => accept(version),
+            None => version == crate::core::Gob::VERSION,
+        }
+    }
+
+    /// Sets whether the layout of the archive is captured as a
+    /// [`RawLayout`](crate::core::RawLayout), so that it is reproduced byte for
+    /// byte when written again. Defaults to `false`.
+    /// 
+    /// The layout is not captured when decompressing files.
+    pub fn preserve_layout(mut self, preserve_layout: bool) -> Self {
+        self.preserve_layout = preserve_layout;
+
+        self
+    }
+
+    /// Sets whether archives which are well-formed, but not canonical, are
+    /// rejected. Defaults to [`ParseMode::Lenient`].
+    /// 
+    /// See [`ParseWarning`](crate::core::ParseWarning) for what makes an archive
+    /// non-canonical, and [`Gob::from_reader_with_warnings`](crate::core::Gob::from_reader_with_warnings)
+    /// for collecting the warnings in lenient mode.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::{io::Cursor, path::PathBuf};
+    /// use gob_rs::{core::{Gob, ParseWarning}, error::GobError, options::{ParseMode, ParseOptions}};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let mut data = gob.as_bytes()?;
+    /// 
+    ///     data.extend(b"TRAILER");
+    /// 
+    ///     assert!(Gob::from_bytes(&data)? == gob);
+    /// 
+    ///     let options = ParseOptions::new().mode(ParseMode::Strict);
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::from_reader_with_options(&mut Cursor::new(data), options),
+    ///         Err(GobError::NonCanonical(ParseWarning::TrailingData { size: 7 })),
+    ///     ));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Sets the codec the data of compressed files within the archive is
+    /// decompressed with. Defaults to [`CompressionCodec::None`]. The parsed
+    /// archive keeps the codec, so it is written compressed again (see
+    /// [`Gob::compression`](crate::core::Gob::compression)). Requires the
+    /// `compression` feature.
+    #[cfg(feature = "compression")]
+    pub fn compression(mut self, compression: CompressionCodec) -> Self {
+        self.compression = compression;
+
+        self
+    }
+}
+
+/// A policy for handling files sharing the same filepath within a GOB archive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Fail with [`GobError::DuplicateFilepath`](crate::error::GobError::DuplicateFilepath).
+    #[default]
+    Error,
+    /// Keep the file defined first.
+    KeepFirst,
+    /// Keep the file defined last, like the games using GOB archives do.
+    KeepLast,
+}
+
+/// A mode for interpreting the offsets of the files within a GOB archive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OffsetMode {
+    /// Offsets are relative to the start of the archive, as written by the
+    /// games using GOB archives.
+    #[default]
+    Absolute,
+    /// Offsets are relative to the end of the file definitions, as written by
+    /// some derivatives of the format.
+    Relative,
+    /// Offsets are relative if, read as absolute ones, any of them would point
+    /// into the header or the file definitions, and absolute otherwise. The
+    /// offsets of files without data are disregarded.
+    Detect,
+}
+
+/// A mode for handling archives which are well-formed, but not canonical.
+/// See [`ParseOptions::mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Parse the archive regardless, like the games using GOB archives do.
+    #[default]
+    Lenient,
+    /// Fail with [`GobError::NonCanonical`](crate::error::GobError::NonCanonical)
+    /// on the first problem found.
+    Strict,
+}
+
+/// Options controlling how directories are packed into GOB archives.
+/// 
+/// # Examples
+/// 
+/// ```no_run
+/// use std::path::Path;
+/// use gob_rs::{core::Gob, options::{DirectoryOptions, SymlinkPolicy}};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let options = DirectoryOptions::new()
+///         .symlinks(SymlinkPolicy::Follow)
+///         .filter(|path| path.extension() != Some("tmp".as_ref()));
+/// 
+///     let gob = Gob::from_directory_with_options(Path::new("/path/to/gob"), options)?;
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct DirectoryOptions<'a> {
+    pub(crate) filter: Option<Filter<'a>>,
+    pub(crate) symlinks: SymlinkPolicy,
+    pub(crate) lenient: bool,
+    pub(crate) metadata: bool,
+    pub(crate) require_non_empty: bool,
+    pub(crate) strip_prefix: Option<PathBuf>,
+}
+
+type Filter<'a> = Box<dyn FnMut(&Path) -> bool + 'a>;
+
+impl<'a> DirectoryOptions<'a> {
+    /// Creates a new [`DirectoryOptions`] object with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a filter deciding which files and directories are included, as
+    /// described by [`Gob::from_directory_with`](crate::core::Gob::from_directory_with).
+    /// Defaults to including everything.
+    pub fn filter<F: FnMut(&Path) -> bool + 'a>(mut self, filter: F) -> Self {
+        self.filter = Some(Box::new(filter));
+
+        self
+    }
+
+    /// Sets how symbolic links within the directory are handled.
+    /// Defaults to [`SymlinkPolicy::Skip`].
+    pub fn symlinks(mut self, symlinks: SymlinkPolicy) -> Self {
+        self.symlinks = symlinks;
+
+        self
+    }
+
+    /// Sets whether entries which are neither files nor directories, e.g. FIFOs,
+    /// sockets or broken symbolic links being followed, are skipped instead of
+    /// failing. Defaults to `false`.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+
+        self
+    }
+
+    /// Sets whether the metadata of packed files is captured, making it
+    /// available through [`Gob::metadata`](crate::core::Gob::metadata).
+    /// Defaults to `false`.
+    pub fn metadata(mut self, metadata: bool) -> Self {
+        self.metadata = metadata;
+
+        self
+    }
+
+    /// Sets whether packing fails with
+    /// [`GobError::EmptySource`](crate::error::GobError::EmptySource) if no
+    /// files are found, e.g. because the directory only contains empty
+    /// directories or everything was filtered out. Defaults to `false`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use gob_rs::{core::Gob, error::GobError, options::DirectoryOptions};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let path = std::env::temp_dir().join("gob_rs_require_non_empty");
+    /// 
+    ///     let _ = std::fs::remove_dir_all(&path);
+    /// 
+    ///     std::fs::create_dir_all(path.join("fizz"))?;
+    /// 
+    ///     assert!(Gob::from_directory(&path)?.is_empty());
+    /// 
+    ///     let options = DirectoryOptions::new().require_non_empty(true);
+    /// 
+    ///     assert!(matches!(
+    ///         Gob::from_directory_with_options(&path, options),
+    ///         Err(GobError::EmptySource(_)),
+    ///     ));
+    /// 
+    ///     std::fs::remove_dir_all(&path)?;
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn require_non_empty(mut self, require_non_empty: bool) -> Self {
+        self.require_non_empty = require_non_empty;
+
+        self
+    }
+
+    /// Sets the prefix stripped from the paths of packed files to get their
+    /// filepaths within the archive, as described by
+    /// [`Gob::from_directory_rooted`](crate::core::Gob::from_directory_rooted).
+    /// Defaults to the path of the packed directory itself.
+    pub fn strip_prefix(mut self, strip_prefix: impl Into<PathBuf>) -> Self {
+        self.strip_prefix = Some(strip_prefix.into());
+
+        self
+    }
+}
+
+impl fmt::Debug for DirectoryOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectoryOptions")
+            .field("filter", &self.filter.as_ref().map(|_| ".."))
+            .field("symlinks", &self.symlinks)
+            .field("lenient", &self.lenient)
+            .field("metadata", &self.metadata)
+            .field("require_non_empty", &self.require_non_empty)
+            .field("strip_prefix", &self.strip_prefix)
+            .finish()
+    }
+}
+
+/// A policy for handling symbolic links while packing a directory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Leave symbolic links out of the archive.
+    #[default]
+    Skip,
+    /// Include the files and directories symbolic links point to, even outside
+    /// of the directory. Symbolic links pointing to a directory currently
+    /// being traversed are skipped to avoid endless recursion.
+    Follow,
+    /// Fail with an error of kind [`InvalidInput`](std::io::ErrorKind::InvalidInput).
+    Error,
+}
@@ -0,0 +1,236 @@
+//! Path module of the library
+
+use std::{ffi::OsStr, path::{Component, Path, PathBuf}};
+
+use crate::format::FilenameEncoding;
+
+/// The separator used by filepaths within GOB archives.
+pub const SEPARATOR: u8 = b'\\';
+
+/// Converts a filepath as stored within a GOB archive into a [`PathBuf`]
+/// using the separator of the platform.
+pub(crate) fn from_gob_str(filepath: &str) -> PathBuf {
+    filepath
+        .split(SEPARATOR as char)
+        .collect()
+}
+
+/// Converts a [`Path`] into the bytes of a filepath as stored within a GOB
+/// archive, joining its components with backslashes.
+/// 
+/// Returns `None` if the path is unrepresentable in the given encoding.
+pub(crate) fn to_gob_bytes(filepath: &Path, encoding: FilenameEncoding) -> Option<Vec<u8>> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for (i, component) in filepath.iter().enumerate() {
+        if i > 0 {
+            bytes.push(SEPARATOR);
+        }
+
+        match encoding {
+            FilenameEncoding::Utf8 => bytes.extend(component.as_encoded_bytes()),
+            encoding => bytes.extend(encoding.encode(component.to_str()?)?),
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Normalizes a filepath the way filepaths are normalized when looking up files
+/// within archives, e.g. with [`Gob::get`](crate::core::Gob::get).
+/// 
+/// Backslashes, the separator within GOB archives, are treated as separators
+/// in addition to the separators of the platform, and the filepath is rebuilt
+/// using the separator of the platform. Filepaths which are not valid UTF-8
+/// are left as they are.
+/// 
+/// The case is preserved; see [`normalize_key`] for folding it. `.` and `..`
+/// components are neither resolved nor rejected, as they are legal within
+/// archives; extracting files rejects filepaths with `..` components instead
+/// (see [`Gob::to_directory`](crate::core::Gob::to_directory)).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use gob_rs::path;
+/// 
+/// let expected: PathBuf = ["mission", "foo.bar"].iter().collect();
+/// 
+/// assert_eq!(path::normalize(Path::new("mission\\foo.bar")), expected);
+/// 
+/// assert_eq!(path::normalize(Path::new("mission/foo.bar")), expected);
+/// 
+/// assert_eq!(path::normalize(Path::new("Mission\\FOO.BAR")), ["Mission", "FOO.BAR"].iter().collect::<PathBuf>());
+/// 
+/// assert_eq!(path::normalize(Path::new("..\\foo.bar")), ["..", "foo.bar"].iter().collect::<PathBuf>());
+/// ```
+pub fn normalize(filepath: &Path) -> PathBuf {
+    match filepath.to_str() {
+        Some(filepath) => from_gob_str(filepath),
+        None => filepath.to_path_buf(),
+    }
+}
+
+/// Normalizes a filepath like [`normalize`] and additionally folds its ASCII
+/// case, such that any two filepaths a case-insensitive archive considers
+/// equal (see [`Gob::set_case_insensitive`](crate::core::Gob::set_case_insensitive))
+/// get the same key.
+/// 
+/// Only ASCII letters are folded, as the games compare filepaths this way.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::Path;
+/// use gob_rs::path;
+/// 
+/// assert_eq!(
+///     path::normalize_key(Path::new("Mission\\FOO.BAR")),
+///     path::normalize_key(Path::new("mission/foo.bar")),
+/// );
+/// 
+/// assert_ne!(
+///     path::normalize_key(Path::new("Ä.bar")),
+///     path::normalize_key(Path::new("ä.bar")),
+/// );
+/// ```
+pub fn normalize_key(filepath: &Path) -> PathBuf {
+    fold_case(&normalize(filepath))
+}
+
+/// Checks whether a filepath stays within the directory it is relative to on
+/// any platform, i.e. it is neither absolute nor contains `..` components.
+/// 
+/// Slashes and backslashes are both treated as separators, and components
+/// containing colons are rejected as drive prefixes, regardless of the
+/// platform, so that archives authored on one platform cannot escape when
+/// extracted on another.
+pub(crate) fn is_contained(filepath: &Path) -> bool {
+    let host_contained = filepath
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+
+    let filepath = filepath.to_string_lossy();
+
+    host_contained
+        && !filepath.starts_with(['/', '\\'])
+        && filepath
+            .split(['/', '\\'])
+            .all(|component| component != ".." && !component.contains(':'))
+}
+
+/// Checks whether two filepaths are equal when ignoring their ASCII case.
+pub(crate) fn eq_ignore_ascii_case(a: &Path, b: &Path) -> bool {
+    a.iter().count() == b.iter().count()
+        && a.iter().zip(b.iter()).all(|(a, b)| a.eq_ignore_ascii_case(b))
+}
+
+/// Folds the ASCII case of a filepath, such that any two filepaths which are
+/// equal when ignoring their ASCII case are folded into the same [`PathBuf`].
+pub(crate) fn fold_case(filepath: &Path) -> PathBuf {
+    filepath
+        .iter()
+        .map(|component| component.to_ascii_lowercase())
+        .collect()
+}
+
+/// Gets the key by which filepaths are sorted when writing deterministically:
+/// the case-folded components, followed by the components as they are.
+pub(crate) fn sort_key(filepath: &Path) -> (PathBuf, PathBuf) {
+    let filepath = normalize(filepath);
+
+    (fold_case(&filepath), filepath)
+}
+
+/// Checks whether a filepath matches a glob pattern, ignoring ASCII case.
+/// 
+/// `?` matches any single character and `*` any sequence of characters within
+/// a component, while `**` also matches across components. Components of the
+/// pattern may be separated by slashes or backslashes.
+pub(crate) fn matches_glob(pattern: &str, filepath: &Path) -> bool {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .map(|character| if character == '/' { SEPARATOR as char } else { character })
+        .collect();
+
+    let mut filepath_chars: Vec<char> = Vec::new();
+
+    for (i, component) in filepath.iter().enumerate() {
+        if i > 0 {
+            filepath_chars.push(SEPARATOR as char);
+        }
+
+        filepath_chars.extend(component.to_string_lossy().chars());
+    }
+
+    matches_glob_chars(&pattern, &filepath_chars)
+}
+
+fn matches_glob_chars(pattern: &[char], filepath: &[char]) -> bool {
+    let separator = SEPARATOR as char;
+
+    match pattern {
+        [] => filepath.is_empty(),
+        ['*', '*', rest @ ..] => {
+            if let [first, rest_after_separator @ ..] = rest {
+                if *first == separator && matches_glob_chars(rest_after_separator, filepath) {
+                    return true;
+                }
+            }
+
+            (0..=filepath.len()).any(|i| matches_glob_chars(rest, &filepath[i..]))
+        }
+        ['*', rest @ ..] => {
+            let component_len = filepath
+                .iter()
+                .position(|&character| character == separator)
+                .unwrap_or(filepath.len());
+
+            (0..=component_len).any(|i| matches_glob_chars(rest, &filepath[i..]))
+        }
+        ['?', rest @ ..] => match filepath {
+            [first, filepath_rest @ ..] if *first != separator => matches_glob_chars(rest, filepath_rest),
+            _ => false,
+        },
+        [character, rest @ ..] => match filepath {
+            [first, filepath_rest @ ..] if first.eq_ignore_ascii_case(character) => matches_glob_chars(rest, filepath_rest),
+            _ => false,
+        },
+    }
+}
+
+/// Checks whether a component of a filepath is a legal filename on the current
+/// platform.
+/// 
+/// Null characters are illegal everywhere. On Windows, the characters
+/// `<>:"|?*`, control characters, trailing dots and spaces, and reserved device
+/// names such as `CON` or `COM1` are illegal as well.
+pub(crate) fn is_legal_component(component: &OsStr) -> bool {
+    if component.as_encoded_bytes().contains(&0) {
+        return false;
+    }
+
+    if !cfg!(windows) {
+        return true;
+    }
+
+    let component = component.to_string_lossy();
+
+    if component.chars().any(|character| character.is_control() || "<>:\"|?*".contains(character)) {
+        return false;
+    }
+
+    if component.ends_with(['.', ' ']) {
+        return false;
+    }
+
+    let stem = component.split('.').next().unwrap_or_default().trim_end().to_ascii_uppercase();
+
+    let reserved = matches!(stem.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || (stem.len() == 4
+            && (stem.starts_with("COM") || stem.starts_with("LPT"))
+            && stem.ends_with(|character: char| character.is_ascii_digit() && character != '0'));
+
+    !reserved
+}
@@ -0,0 +1,118 @@
+//! Serialize module of the library
+
+use std::fmt;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{
+    de::{self, MapAccess, Visitor}, ser::{self, SerializeMap}, Deserialize, Deserializer, Serialize, Serializer
+};
+
+use crate::{core::{Gob, GobMap}, format::FilenameEncoding, path};
+
+/// Serializes the files of a [`Gob`] object as a map of filepaths to data.
+/// 
+/// Filepaths are serialized as strings with their components joined by
+/// backslashes, as within the archive, so that the keys are the same on every
+/// platform. Human-readable formats, e.g. JSON, receive the data
+/// base64-encoded, while binary formats receive it as bytes. The settings of
+/// the archive are not serialized.
+/// 
+/// Requires the `serde` feature.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use gob_rs::core::Gob;
+/// 
+/// let mut gob = Gob::new();
+/// 
+/// gob.files.insert(
+///     PathBuf::from("foo.bar"),
+///     b"foobar".to_vec(),
+/// );
+/// 
+/// gob.files.insert(
+///     ["mission", "fizz.buzz"].iter().collect(),
+///     b"fizzbuzz".to_vec(),
+/// );
+/// 
+/// let json = serde_json::to_string(&gob).unwrap();
+/// 
+/// assert_eq!(json, r#"{"foo.bar":"Zm9vYmFy","mission\\fizz.buzz":"Zml6emJ1eno="}"#);
+/// 
+/// let parsed: Gob = serde_json::from_str(&json).unwrap();
+/// 
+/// assert!(parsed == gob);
+/// 
+/// assert_eq!(parsed.as_bytes().unwrap(), gob.as_bytes().unwrap());
+/// ```
+impl Serialize for Gob {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let human_readable = serializer.is_human_readable();
+
+        let mut map = serializer.serialize_map(Some(self.files.len()))?;
+
+        for (filepath, data) in &self.files {
+            let filepath = match path::to_gob_bytes(filepath, FilenameEncoding::Utf8).map(String::from_utf8) {
+                Some(Ok(filepath)) => filepath,
+                _ => {
+                    return Err(ser::Error::custom(format!("Filepath is not valid UTF-8: {}", filepath.display())));
+                }
+            };
+
+            if human_readable {
+                map.serialize_entry(&filepath, &STANDARD.encode(data))?;
+            } else {
+                map.serialize_entry(&filepath, data)?;
+            }
+        }
+
+        map.end()
+    }
+}
+
+/// Deserializes a [`Gob`] object from a map of filepaths to data, as produced
+/// by its [`Serialize`] implementation. Backslashes within filepaths are
+/// converted into the separator of the platform.
+/// 
+/// Requires the `serde` feature.
+impl<'de> Deserialize<'de> for Gob {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let human_readable = deserializer.is_human_readable();
+
+        deserializer.deserialize_map(GobVisitor { human_readable })
+    }
+}
+
+struct GobVisitor {
+    human_readable: bool,
+}
+
+impl<'de> Visitor<'de> for GobVisitor {
+    type Value = Gob;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map of filepaths to data")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut files = GobMap::with_capacity(access.size_hint().unwrap_or(0));
+
+        while let Some(filepath) = access.next_key::<String>()? {
+            let filepath = path::from_gob_str(&filepath);
+
+            let data = if self.human_readable {
+                let data = access.next_value::<String>()?;
+
+                STANDARD.decode(data).map_err(de::Error::custom)?
+            } else {
+                access.next_value::<Vec<u8>>()?
+            };
+
+            files.insert(filepath, data);
+        }
+
+        Ok(Gob::from(files))
+    }
+}
@@ -0,0 +1,215 @@
+//! View module of the library
+
+use std::{
+    borrow::Cow, io::{Cursor, Seek, Write}, path::{Path, PathBuf}
+};
+
+use indexmap::IndexMap;
+
+use crate::{core::{FileDefinition, Gob, GobMap, ParsedTable}, error::GobError, format::GobFormat, options::{DuplicatePolicy, ParseOptions}, path, writer::GobWriter};
+
+/// A view of a GOB archive whose data of files may be borrowed from a buffer
+/// holding the archive, avoiding copying it for read-only use.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::{borrow::Cow, path::PathBuf};
+/// use gob_rs::core::Gob;
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut gob = Gob::new();
+/// 
+///     gob.files.insert(
+///         PathBuf::from("foo.bar"),
+///         b"foobar".to_vec(),
+///     );
+/// 
+///     let data = gob.as_bytes()?;
+/// 
+///     let mut view = Gob::from_bytes_borrowed(&data)?;
+/// 
+///     assert!(matches!(view.files[0], Cow::Borrowed(b"foobar")));
+/// 
+///     view.files.insert(PathBuf::from("fizz.buzz"), Cow::Owned(b"fizzbuzz".to_vec()));
+/// 
+///     let gob = view.into_owned();
+/// 
+///     assert_eq!(gob.len(), 2);
+/// 
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GobView<'a> {
+    /// The files of the archive, mapping filepaths to data borrowed from the
+    /// buffer or owned, in the order they are stored in.
+    pub files: IndexMap<PathBuf, Cow<'a, [u8]>>,
+    format: GobFormat,
+}
+
+impl<'a> GobView<'a> {
+    /// Creates a new [`GobView`] object from the data of a GOB archive in
+    /// memory, parsing it according to given [`ParseOptions`] and borrowing the
+    /// data of the files from it.
+    /// 
+    /// The data of the files is neither decompressed nor checked for
+    /// compression.
+    pub fn with_options(data: &'a [u8], options: ParseOptions) -> Result<Self, GobError> {
+        let ParsedTable {
+            format,
+            file_definitions,
+            ..
+        } = Gob::read_file_definitions(&mut Cursor::new(data), &options)?;
+
+        Ok(Self::from_definitions(data, format, &file_definitions, options.duplicates))
+    }
+
+    /// Creates a new [`GobView`] object borrowing the data of the files defined
+    /// by given file definitions from a given buffer.
+    /// 
+    /// The file definitions must have been parsed from the buffer, so that they
+    /// are known to be within its bounds.
+    pub(crate) fn from_definitions(
+        data: &'a [u8],
+        format: GobFormat,
+        file_definitions: &[FileDefinition],
+        duplicates: DuplicatePolicy,
+    ) -> Self {
+        let mut files: IndexMap<PathBuf, Cow<'a, [u8]>> = IndexMap::with_capacity(file_definitions.len());
+
+        for file_definition in file_definitions {
+            if duplicates == DuplicatePolicy::KeepFirst && files.contains_key(&file_definition.filepath) {
+                continue;
+            }
+
+            let start = file_definition.offset as usize;
+
+            let end = start + file_definition.size as usize;
+
+            files.insert(file_definition.filepath.clone(), Cow::Borrowed(&data[start..end]));
+        }
+
+        Self {
+            files,
+            format,
+        }
+    }
+
+    /// Gets the variant of the GOB format the archive is written in.
+    pub fn format(&self) -> GobFormat {
+        self.format
+    }
+
+    /// Gets the data of the file at a given path within the archive.
+    /// 
+    /// The path may be separated by slashes or backslashes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::path::PathBuf;
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("mission/foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let view = Gob::from_bytes_borrowed(&data)?;
+    /// 
+    ///     assert_eq!(view.get("mission\\foo.bar"), Some(&b"foobar"[..]));
+    /// 
+    ///     assert_eq!(view.get("fizz.buzz"), None);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&[u8]> {
+        self.files.get(&path::normalize(path.as_ref())).map(|data| data.as_ref())
+    }
+
+    /// Gets the amount of files in the archive.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Checks whether the archive contains no files.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Writes the archive to a given writer, using a [`GobWriter`], without
+    /// copying borrowed data of files into owned buffers first.
+    /// 
+    /// The file definitions are written after the data of the files, so the
+    /// layout may differ from the one of the buffer the view borrows from.
+    /// 
+    /// # Examples
+    /// 
+    /// Replaces a single file of an archive, passing the others through:
+    /// 
+    /// ```
+    /// use std::{borrow::Cow, io::Cursor, path::PathBuf};
+    /// use gob_rs::core::Gob;
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut gob = Gob::new();
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("foo.bar"),
+    ///         b"foobar".to_vec(),
+    ///     );
+    /// 
+    ///     gob.files.insert(
+    ///         PathBuf::from("fizz.buzz"),
+    ///         b"fizzbuzz".to_vec(),
+    ///     );
+    /// 
+    ///     let data = gob.as_bytes()?;
+    /// 
+    ///     let mut view = Gob::from_bytes_borrowed(&data)?;
+    /// 
+    ///     view.files.insert(PathBuf::from("foo.bar"), Cow::Owned(b"foobaz".to_vec()));
+    /// 
+    ///     let written = view.write_to(Cursor::new(Vec::new()))?.into_inner();
+    /// 
+    ///     let parsed = Gob::from_bytes(&written)?;
+    /// 
+    ///     assert_eq!(parsed.get("foo.bar"), Some(&b"foobaz"[..]));
+    /// 
+    ///     assert_eq!(parsed.get("fizz.buzz"), Some(&b"fizzbuzz"[..]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_to<W: Write + Seek>(&self, writer: W) -> Result<W, GobError> {
+        let mut writer = GobWriter::with_format(writer, self.format)?;
+
+        for (filepath, data) in &self.files {
+            writer.add_file(filepath.clone(), &mut data.as_ref())?;
+        }
+
+        writer.finish()
+    }
+
+    /// Consumes the [`GobView`] object, copying borrowed data of files into a
+    /// new [`Gob`] object.
+    pub fn into_owned(self) -> Gob {
+        let files: GobMap = self.files
+            .into_iter()
+            .map(|(filepath, data)| (filepath, data.into_owned()))
+            .collect();
+
+        let mut gob = Gob::from(files);
+
+        gob.set_format(self.format);
+
+        gob
+    }
+}
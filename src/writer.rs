@@ -0,0 +1,302 @@
+//! Writer module of the library
+
+use std::{
+    collections::HashSet, io::{self, Read, Seek, SeekFrom, Write}, path::PathBuf
+};
+
+use crate::{core::{FileDefinition, Gob}, error::GobError, format::{FilenameEncoding, GobFormat}, path};
+
+/// A writer producing a GOB archive incrementally, writing the data of each
+/// file as it is added instead of holding all files in memory.
+/// 
+/// The file definitions are written after the data of the files once the
+/// writer is finished, and the offset in the header is patched to point to
+/// them. An archive whose writer is dropped without being finished is invalid.
+/// 
+/// If writing the data of a file fails, the writer is poisoned: the data may
+/// be written partially, so any further call to [`GobWriter::add_file`] or
+/// [`GobWriter::finish`] fails with [`GobError::Poisoned`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::io::Cursor;
+/// use gob_rs::{core::Gob, writer::GobWriter};
+/// 
+/// fn main() -> std::io::Result<()> {
+///     let mut writer = GobWriter::new(Cursor::new(Vec::new()))?;
+/// 
+///     writer.add_file("foo.bar", &mut &b"foobar"[..])?;
+/// 
+///     writer.add_file("fizz/fizz.buzz", &mut &b"fizzbuzz"[..])?;
+/// 
+///     let data = writer.finish()?.into_inner();
+/// 
+///     let gob = Gob::from_bytes(&data)?;
+/// 
+///     assert_eq!(gob.get("foo.bar"), Some(&b"foobar"[..]));
+/// 
+///     assert_eq!(gob.get("fizz/fizz.buzz"), Some(&b"fizzbuzz"[..]));
+/// 
+///     Ok(())
+/// }
+/// ```
+pub struct GobWriter<W: Write + Seek> {
+    writer: W,
+    format: GobFormat,
+    filename_encoding: FilenameEncoding,
+    start: u64,
+    offset: u64,
+    entries: Vec<FileDefinition>,
+    filepaths: HashSet<PathBuf>,
+    poisoned: bool,
+}
+
+impl<W: Write + Seek> GobWriter<W> {
+    /// Creates a new [`GobWriter`] object writing a GOB archive of the
+    /// [`GobFormat::JediKnight`] variant into a given writer, starting at its
+    /// current position.
+    pub fn new(writer: W) -> Result<Self, GobError> {
+        Self::with_format(writer, GobFormat::default())
+    }
+
+    /// Creates a new [`GobWriter`] object writing a GOB archive of a given
+    /// variant into a given writer, starting at its current position.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use gob_rs::{core::Gob, format::GobFormat, writer::GobWriter};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut writer = GobWriter::with_format(Cursor::new(Vec::new()), GobFormat::DarkForces)?;
+    /// 
+    ///     writer.add_file("FOO.BAR", &mut &b"foobar"[..])?;
+    /// 
+    ///     let gob = Gob::from_bytes(&writer.finish()?.into_inner())?;
+    /// 
+    ///     assert_eq!(gob.format(), GobFormat::DarkForces);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_format(mut writer: W, format: GobFormat) -> Result<Self, GobError> {
+        let start = writer.stream_position()?;
+
+        writer.write_all(format.signature())?;
+
+        if format == GobFormat::JediKnight {
+            writer.write_all(&Gob::VERSION.to_le_bytes())?;
+        }
+
+        // Patched to the offset of the file definitions once finished.
+        writer.write_all(&0u32.to_le_bytes())?;
+
+        let offset = writer.stream_position()? - start;
+
+        Ok(Self {
+            writer,
+            format,
+            filename_encoding: FilenameEncoding::default(),
+            start,
+            offset,
+            entries: Vec::new(),
+            filepaths: HashSet::new(),
+            poisoned: false,
+        })
+    }
+
+    /// Sets the encoding the filepaths of subsequently added files are encoded
+    /// with. Defaults to [`FilenameEncoding::Utf8`].
+    pub fn set_filename_encoding(&mut self, filename_encoding: FilenameEncoding) {
+        self.filename_encoding = filename_encoding;
+    }
+
+    /// Adds a file at a given path within the archive, copying its data from a
+    /// given reader into the archive.
+    /// 
+    /// The filepath is checked before any data is written.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use gob_rs::{error::GobError, writer::GobWriter};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut writer = GobWriter::new(Cursor::new(Vec::new()))?;
+    /// 
+    ///     writer.add_file("foo.bar", &mut &b"foobar"[..])?;
+    /// 
+    ///     assert!(matches!(
+    ///         writer.add_file("foo.bar", &mut &b"foobaz"[..]),
+    ///         Err(GobError::DuplicateFilepath(_)),
+    ///     ));
+    /// 
+    ///     assert!(matches!(
+    ///         writer.add_file("../foo.bar", &mut &b"foobar"[..]),
+    ///         Err(GobError::UnsafePath(_)),
+    ///     ));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Packs files whose data is produced lazily, without any buffer holding the
+    /// data of a whole file:
+    /// 
+    /// ```
+    /// use std::io::{self, Cursor, Read};
+    /// use gob_rs::{core::Gob, writer::GobWriter};
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut writer = GobWriter::new(Cursor::new(Vec::new()))?;
+    /// 
+    ///     let sources = [("zeros.raw", 0, 1000), ("ones.raw", 1, 500)];
+    /// 
+    ///     for (filepath, byte, size) in sources {
+    ///         writer.add_file(filepath, &mut io::repeat(byte).take(size))?;
+    ///     }
+    /// 
+    ///     let gob = Gob::from_bytes(&writer.finish()?.into_inner())?;
+    /// 
+    ///     assert_eq!(gob.get("zeros.raw").map(|data| data.len()), Some(1000));
+    /// 
+    ///     assert_eq!(gob.get("ones.raw"), Some(&[1; 500][..]));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    /// 
+    /// Fails for good once reading the data of a file fails:
+    /// 
+    /// ```
+    /// use std::io::{self, Cursor, Read};
+    /// use gob_rs::{error::GobError, writer::GobWriter};
+    /// 
+    /// struct Failing;
+    /// 
+    /// impl Read for Failing {
+    ///     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+    ///         Err(io::Error::other("broken source"))
+    ///     }
+    /// }
+    /// 
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut writer = GobWriter::new(Cursor::new(Vec::new()))?;
+    /// 
+    ///     let mut data = (&b"foo"[..]).chain(Failing);
+    /// 
+    ///     assert!(matches!(writer.add_file("foo.bar", &mut data), Err(GobError::Io(_))));
+    /// 
+    ///     assert!(matches!(writer.add_file("fizz.buzz", &mut &b"fizzbuzz"[..]), Err(GobError::Poisoned)));
+    /// 
+    ///     assert!(matches!(writer.finish(), Err(GobError::Poisoned)));
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_file<R: Read>(&mut self, path: impl Into<PathBuf>, reader: &mut R) -> Result<(), GobError> {
+        if self.poisoned {
+            return Err(GobError::Poisoned);
+        }
+
+        let filepath = path.into();
+
+        Gob::encode_filepath(&filepath, self.filename_encoding, self.format)?;
+
+        let filepath = path::normalize(&filepath);
+
+        if self.filepaths.contains(&filepath) {
+            return Err(GobError::DuplicateFilepath(filepath));
+        }
+
+        if self.offset > u32::MAX as u64 {
+            return Err(GobError::SizeOverflow {
+                path: filepath,
+                size: self.offset,
+            });
+        }
+
+        // Cleared once the data is accounted for, so that later offsets are right.
+        self.poisoned = true;
+
+        let size = io::copy(reader, &mut self.writer)?;
+
+        if size > u32::MAX as u64 {
+            return Err(GobError::SizeOverflow {
+                path: filepath,
+                size,
+            });
+        }
+
+        self.filepaths.insert(filepath.clone());
+
+        self.entries.push(FileDefinition {
+            offset: self.offset,
+            size,
+            filepath,
+        });
+
+        self.offset += size;
+
+        self.poisoned = false;
+
+        Ok(())
+    }
+
+    /// Writes the file definitions and patches the header, returning the
+    /// underlying writer positioned at the end of the archive.
+    pub fn finish(mut self) -> Result<W, GobError> {
+        if self.poisoned {
+            return Err(GobError::Poisoned);
+        }
+
+        let table_offset = match u32::try_from(self.offset) {
+            Ok(table_offset) => table_offset,
+            Err(_) => {
+                let filepath = self.entries.last().map(|entry| entry.filepath.clone()).unwrap_or_default();
+
+                return Err(GobError::SizeOverflow {
+                    path: filepath,
+                    size: self.offset,
+                });
+            }
+        };
+
+        let filepath_len = self.format.filepath_len();
+
+        self.writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+
+        for entry in &self.entries {
+            self.writer.write_all(&(entry.offset as u32).to_le_bytes())?;
+
+            self.writer.write_all(&(entry.size as u32).to_le_bytes())?;
+
+            let filepath_bytes = path::to_gob_bytes(&entry.filepath, self.filename_encoding)
+                .expect("Filepath should have been checked when added");
+
+            self.writer.write_all(&filepath_bytes)?;
+
+            self.writer.write_all(&vec![0; filepath_len - filepath_bytes.len()])?;
+        }
+
+        let end = self.writer.stream_position()?;
+
+        let header_offset = match self.format {
+            GobFormat::DarkForces => 4,
+            GobFormat::JediKnight => 8,
+        };
+
+        self.writer.seek(SeekFrom::Start(self.start + header_offset))?;
+
+        self.writer.write_all(&table_offset.to_le_bytes())?;
+
+        self.writer.seek(SeekFrom::Start(end))?;
+
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}